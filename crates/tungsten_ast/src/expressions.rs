@@ -0,0 +1,243 @@
+use tungsten_lexer::PrimitiveType;
+use tungsten_utils::Atom;
+
+use crate::Span;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ident {
+    pub name: Atom,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expr {
+    pub kind: ExprKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExprKind {
+    Literal(Literal),
+    Identifier(Ident),
+    Unary {
+        op: UnaryOp,
+        operand: Box<Expr>,
+    },
+    Binary {
+        op: BinaryOp,
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+    },
+    /// Plain or compound assignment, eg: `a = b` or `a += b`
+    Assign {
+        op: AssignOp,
+        target: Box<Expr>,
+        value: Box<Expr>,
+    },
+    Call {
+        callee: Box<Expr>,
+        args: Vec<Expr>,
+    },
+    Index {
+        target: Box<Expr>,
+        index: Box<Expr>,
+    },
+    Member {
+        target: Box<Expr>,
+        member: Ident,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Integer(u64, Option<PrimitiveType>),
+    Float(f64, Option<PrimitiveType>),
+    String(Atom),
+    Character(char),
+    Boolean(bool),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+    /// -
+    Neg,
+    /// !
+    Not,
+    /// ~
+    BitNot,
+    /// ++a
+    PreIncrement,
+    /// --a
+    PreDecrement,
+    /// a++
+    PostIncrement,
+    /// a--
+    PostDecrement,
+}
+
+impl UnaryOp {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnaryOp::Neg => "-",
+            UnaryOp::Not => "!",
+            UnaryOp::BitNot => "~",
+            UnaryOp::PreIncrement | UnaryOp::PostIncrement => "++",
+            UnaryOp::PreDecrement | UnaryOp::PostDecrement => "--",
+        }
+    }
+
+    pub fn is_postfix(&self) -> bool {
+        matches!(self, UnaryOp::PostIncrement | UnaryOp::PostDecrement)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    /// +
+    Add,
+    /// -
+    Sub,
+    /// *
+    Mul,
+    /// /
+    Div,
+    /// //
+    FloorDiv,
+    /// %
+    Rem,
+    /// **
+    Pow,
+    /// <>, converting non-string operands to strings. Interpolated strings desugar to it.
+    Concat,
+    /// <<
+    Shl,
+    /// >>
+    Shr,
+    /// &
+    BitAnd,
+    /// |
+    BitOr,
+    /// ^
+    BitXor,
+    /// ==
+    Eq,
+    /// !=
+    NotEq,
+    /// <
+    Less,
+    /// <=
+    LessEq,
+    /// >
+    Greater,
+    /// >=
+    GreaterEq,
+    /// &&
+    And,
+    /// ||
+    Or,
+    /// ..
+    Range,
+    /// ..=
+    RangeInclusive,
+}
+
+impl BinaryOp {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Sub => "-",
+            BinaryOp::Mul => "*",
+            BinaryOp::Div => "/",
+            BinaryOp::FloorDiv => "//",
+            BinaryOp::Rem => "%",
+            BinaryOp::Pow => "**",
+            BinaryOp::Concat => "<>",
+            BinaryOp::Shl => "<<",
+            BinaryOp::Shr => ">>",
+            BinaryOp::BitAnd => "&",
+            BinaryOp::BitOr => "|",
+            BinaryOp::BitXor => "^",
+            BinaryOp::Eq => "==",
+            BinaryOp::NotEq => "!=",
+            BinaryOp::Less => "<",
+            BinaryOp::LessEq => "<=",
+            BinaryOp::Greater => ">",
+            BinaryOp::GreaterEq => ">=",
+            BinaryOp::And => "&&",
+            BinaryOp::Or => "||",
+            BinaryOp::Range => "..",
+            BinaryOp::RangeInclusive => "..=",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssignOp {
+    /// =
+    Assign,
+    /// +=
+    Add,
+    /// -=
+    Sub,
+    /// *=
+    Mul,
+    /// /=
+    Div,
+    /// //=
+    FloorDiv,
+    /// %=
+    Rem,
+    /// **=
+    Pow,
+    /// <<=
+    Shl,
+    /// >>=
+    Shr,
+    /// &=
+    BitAnd,
+    /// |=
+    BitOr,
+    /// ^=
+    BitXor,
+}
+
+impl AssignOp {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AssignOp::Assign => "=",
+            AssignOp::Add => "+=",
+            AssignOp::Sub => "-=",
+            AssignOp::Mul => "*=",
+            AssignOp::Div => "/=",
+            AssignOp::FloorDiv => "//=",
+            AssignOp::Rem => "%=",
+            AssignOp::Pow => "**=",
+            AssignOp::Shl => "<<=",
+            AssignOp::Shr => ">>=",
+            AssignOp::BitAnd => "&=",
+            AssignOp::BitOr => "|=",
+            AssignOp::BitXor => "^=",
+        }
+    }
+
+    /// The binary operator a compound assignment applies, `None` for plain `=`
+    pub fn binary_op(&self) -> Option<BinaryOp> {
+        let op = match self {
+            AssignOp::Assign => return None,
+            AssignOp::Add => BinaryOp::Add,
+            AssignOp::Sub => BinaryOp::Sub,
+            AssignOp::Mul => BinaryOp::Mul,
+            AssignOp::Div => BinaryOp::Div,
+            AssignOp::FloorDiv => BinaryOp::FloorDiv,
+            AssignOp::Rem => BinaryOp::Rem,
+            AssignOp::Pow => BinaryOp::Pow,
+            AssignOp::Shl => BinaryOp::Shl,
+            AssignOp::Shr => BinaryOp::Shr,
+            AssignOp::BitAnd => BinaryOp::BitAnd,
+            AssignOp::BitOr => BinaryOp::BitOr,
+            AssignOp::BitXor => BinaryOp::BitXor,
+        };
+
+        Some(op)
+    }
+}
@@ -0,0 +1,60 @@
+use tungsten_utils::Atom;
+
+use crate::{Binding, Block, Ident, Span, Type};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Program {
+    pub items: Vec<Item>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub kind: ItemKind,
+    pub visibility: Visibility,
+    /// Text of the `##` doc comments preceding the item, one line per comment
+    pub doc: Option<Atom>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visibility {
+    Private,
+    /// pub
+    Public,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ItemKind {
+    Function(Function),
+    /// Global `var` or `const` binding
+    Binding(Binding),
+    /// `module a;`, declaring the submodule `a` of the current module
+    Module(Ident),
+    /// `import a::b;`
+    Import(ModulePath),
+    /// Item that failed to parse, the error has already been reported
+    Error,
+}
+
+/// `::` separated path to a module from the root module, eg: `a::b`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModulePath {
+    pub segments: Vec<Ident>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Function {
+    pub name: Ident,
+    pub params: Vec<Param>,
+    pub return_type: Option<Type>,
+    pub body: Block,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Param {
+    pub name: Ident,
+    pub ty: Type,
+    pub span: Span,
+}
@@ -0,0 +1,11 @@
+pub use expressions::*;
+pub use items::*;
+pub use span::*;
+pub use statements::*;
+pub use types::*;
+
+mod expressions;
+mod items;
+mod span;
+mod statements;
+mod types;
@@ -0,0 +1,2 @@
+/// Byte range into the source file a node was parsed from.
+pub use tungsten_context::Span;
@@ -0,0 +1,119 @@
+use tungsten_utils::Atom;
+
+use crate::{Expr, Ident, Literal, Span, Type};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Block {
+    pub statements: Vec<Stmt>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stmt {
+    pub kind: StmtKind,
+    /// Text of the `##` doc comments preceding a local binding, one line per comment
+    pub doc: Option<Atom>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StmtKind {
+    Binding(Binding),
+    Expr(Expr),
+    Block(Block),
+    If(IfStmt),
+    While(WhileStmt),
+    For(ForStmt),
+    Loop(Block),
+    Repeat(RepeatStmt),
+    Match(MatchStmt),
+    Defer(Box<Stmt>),
+    Break,
+    Continue,
+    /// |>
+    Return(Option<Expr>),
+    /// Statement that failed to parse, the error has already been reported
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mutability {
+    /// const
+    Const,
+    /// var
+    Var,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Binding {
+    pub mutability: Mutability,
+    pub name: Ident,
+    pub ty: Option<Type>,
+    pub value: Option<Expr>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IfStmt {
+    pub condition: Expr,
+    pub then_branch: Block,
+    pub else_branch: Option<Box<ElseBranch>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ElseBranch {
+    /// else if
+    If(IfStmt),
+    Block(Block),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WhileStmt {
+    pub condition: Expr,
+    pub body: Block,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForStmt {
+    pub binding: Ident,
+    pub iterable: Expr,
+    pub body: Block,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepeatStmt {
+    pub body: Block,
+    pub condition: Expr,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchStmt {
+    pub scrutinee: Expr,
+    pub arms: Vec<MatchArm>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchArm {
+    pub pattern: Pattern,
+    pub body: Box<Stmt>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern {
+    pub kind: PatternKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatternKind {
+    /// _
+    Wildcard,
+    Literal(Literal),
+    Binding(Ident),
+    /// Eg: `0..10` or `0..=9`
+    Range {
+        start: Literal,
+        end: Literal,
+        inclusive: bool,
+    },
+}
@@ -0,0 +1,17 @@
+use tungsten_lexer::PrimitiveType;
+
+use crate::{Ident, Span};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Type {
+    pub kind: TypeKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeKind {
+    /// A built-in type, eg: `int` or `u8`
+    Primitive(PrimitiveType),
+    /// A type referred to by name, eg: `Point`
+    Named(Ident),
+}
@@ -1,15 +1,49 @@
-use std::ops::Range;
-
 use codespan_reporting::diagnostic::{Diagnostic, Label};
 
+use crate::{FileId, Span};
+
 const NON_ASCII_CHARACTER_CODE: &str = "001";
 const UNTERMINATED_STRING_CODE: &str = "002";
 const INVALID_ESCAPE_SEQUENCE_CODE: &str = "003";
 const ILLEGAL_CHARACTER_CODE: &str = "004";
 const UNEXPECTED_END_CODE: &str = "005";
 const INVALID_UNICODE_CODEPOINT: &str = "006";
+const UNEXPECTED_TOKEN_CODE: &str = "007";
+const EXPECTED_EXPRESSION_CODE: &str = "008";
+const INVALID_ASSIGNMENT_TARGET_CODE: &str = "009";
+const NON_ASSOCIATIVE_OPERATOR_CODE: &str = "010";
+const UNTERMINATED_BLOCK_COMMENT_CODE: &str = "011";
+const INVALID_DIGIT_CODE: &str = "012";
+const EMPTY_RADIX_LITERAL_CODE: &str = "013";
+const INTEGER_OUT_OF_RANGE_CODE: &str = "014";
+const FLOAT_OUT_OF_RANGE_CODE: &str = "015";
+const MALFORMED_FLOAT_CODE: &str = "016";
+const INVALID_SUFFIX_CODE: &str = "017";
+const MISMATCHED_SUFFIX_CODE: &str = "018";
+const SUFFIX_OUT_OF_RANGE_CODE: &str = "019";
+const UNTERMINATED_CHAR_CODE: &str = "020";
+const EMPTY_CHAR_CODE: &str = "021";
+const MULTIPLE_CHARACTERS_CODE: &str = "022";
+const UNTERMINATED_RAW_STRING_CODE: &str = "023";
+const UNTERMINATED_MULTILINE_STRING_CODE: &str = "024";
+const EMPTY_INTERPOLATION_CODE: &str = "025";
+const UNTERMINATED_INTERPOLATION_CODE: &str = "026";
+const UNESCAPED_BRACE_CODE: &str = "027";
+const UNEXPECTED_CHARACTER_CODE: &str = "028";
+const CONFUSABLE_CHARACTER_CODE: &str = "029";
+const INVISIBLE_CHARACTER_CODE: &str = "030";
+const SURROGATE_ESCAPE_CODE: &str = "031";
+const UNRESOLVED_MODULE_CODE: &str = "032";
+const IMPORT_CYCLE_CODE: &str = "033";
+const UNDECLARED_NAME_CODE: &str = "034";
+const DUPLICATE_DEFINITION_CODE: &str = "035";
+const FRACTIONAL_RADIX_LITERAL_CODE: &str = "036";
 
-pub fn build_non_ascii_character_error(span: Range<usize>, ch: char) -> Diagnostic<()> {
+const UNATTACHED_DOC_COMMENT_CODE: &str = "001";
+const CONFUSABLE_IDENTIFIER_CODE: &str = "002";
+const SHADOWED_NAME_CODE: &str = "003";
+
+pub fn build_non_ascii_character_error(span: Span, ch: char) -> Diagnostic<FileId> {
     Diagnostic::error()
         .with_message(format!("Encountered non-ASCII character `{ch}`"))
         .with_code(format!("E{NON_ASCII_CHARACTER_CODE}"))
@@ -18,11 +52,88 @@ pub fn build_non_ascii_character_error(span: Range<usize>, ch: char) -> Diagnost
             format!("`{ch}` is not a ASCII character"),
         ])
         .with_labels(vec![
-            Label::primary((), span).with_message("illegal character found here")
+            Label::primary(span.file, span).with_message("illegal character found here")
+        ])
+}
+
+pub fn build_unexpected_character_error(span: Span, ch: char) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message(format!("Encountered unexpected character `{ch}`"))
+        .with_code(format!("E{UNEXPECTED_CHARACTER_CODE}"))
+        .with_notes(vec![format!(
+            "`{ch}` (U+{:04X}) cannot start a token or an identifier",
+            ch as u32
+        )])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("unexpected character found here")
+        ])
+}
+
+pub fn build_confusable_character_error(
+    span: Span,
+    ch: char,
+    name: &str,
+    suggestion: char,
+) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message(format!(
+            "Encountered `{ch}` ({name}), which looks like `{suggestion}`"
+        ))
+        .with_code(format!("E{CONFUSABLE_CHARACTER_CODE}"))
+        .with_notes(vec![format!(
+            "`{ch}` is U+{:04X}, `{suggestion}` is U+{:04X}",
+            ch as u32, suggestion as u32
+        )])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message(format!("did you mean `{suggestion}`?"))
+        ])
+}
+
+pub fn build_confusable_identifier_warning(
+    span: Span,
+    identifier: &str,
+    ch: char,
+    name: &str,
+    suggestion: char,
+) -> Diagnostic<FileId> {
+    Diagnostic::warning()
+        .with_message(format!(
+            "Identifier `{identifier}` contains `{ch}` ({name}), which looks like `{suggestion}`"
+        ))
+        .with_code(format!("W{CONFUSABLE_IDENTIFIER_CODE}"))
+        .with_notes(vec![
+            "Mixing lookalike characters with ASCII letters makes identifiers that read the same but differ"
+                .to_string(),
+        ])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message(format!("did you mean `{suggestion}`?"))
         ])
 }
 
-pub fn build_illegal_character_error(span: Range<usize>, ch: char, ctx: &str) -> Diagnostic<()> {
+pub fn build_invisible_character_error(
+    span: Span,
+    ch: char,
+    name: &str,
+    in_literal: bool,
+) -> Diagnostic<FileId> {
+    let code_point = format!("U+{:04X}", ch as u32);
+    let suggestion = if in_literal {
+        format!("did you mean `\\u{{{:X}}}`?", ch as u32)
+    } else {
+        "did you mean to remove it?".to_string()
+    };
+
+    Diagnostic::error()
+        .with_message(format!("Encountered invisible character {code_point} ({name})"))
+        .with_code(format!("E{INVISIBLE_CHARACTER_CODE}"))
+        .with_notes(vec![
+            "Invisible and bidirectional control characters can make source code read differently from how it compiles"
+                .to_string(),
+        ])
+        .with_labels(vec![Label::primary(span.file, span).with_message(suggestion)])
+}
+
+pub fn build_illegal_character_error(span: Span, ch: char, ctx: &str) -> Diagnostic<FileId> {
     Diagnostic::error()
         .with_message(format!("Encountered illegal character `{ch}` in {ctx}"))
         .with_code(format!("E{ILLEGAL_CHARACTER_CODE}"))
@@ -31,11 +142,24 @@ pub fn build_illegal_character_error(span: Range<usize>, ch: char, ctx: &str) ->
             // format!("`{ch}` is not a ASCII character"),
         ])
         .with_labels(vec![
-            Label::primary((), span).with_message("illegal character found here")
+            Label::primary(span.file, span).with_message("illegal character found here")
+        ])
+}
+
+pub fn build_surrogate_escape_error(span: Span) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message("Encountered a unicode escape for a surrogate code point")
+        .with_code(format!("E{SURROGATE_ESCAPE_CODE}"))
+        .with_notes(vec![
+            "Code points from U+D800 to U+DFFF are reserved for UTF-16 and are not characters"
+                .to_string(),
+        ])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("surrogate escape found here")
         ])
 }
 
-pub fn build_invalid_unicode_codepoint_error(span: Range<usize>, ch: &str) -> Diagnostic<()> {
+pub fn build_invalid_unicode_codepoint_error(span: Span, ch: &str) -> Diagnostic<FileId> {
     Diagnostic::error()
         .with_message(format!("Encountered invalid unicode codepoint `{ch}`"))
         .with_code(format!("E{INVALID_UNICODE_CODEPOINT}"))
@@ -44,11 +168,11 @@ pub fn build_invalid_unicode_codepoint_error(span: Range<usize>, ch: &str) -> Di
             // format!("`{ch}` is not a ASCII character"),
         ])
         .with_labels(vec![
-            Label::primary((), span).with_message("invalid unicode codepoint found here")
+            Label::primary(span.file, span).with_message("invalid unicode codepoint found here")
         ])
 }
 
-pub fn build_unexpected_end_error(span: Range<usize>, ctx: &str) -> Diagnostic<()> {
+pub fn build_unexpected_end_error(span: Span, ctx: &str) -> Diagnostic<FileId> {
     Diagnostic::error()
         .with_message(format!("Encountered unexpected end in {ctx}"))
         .with_code(format!("E{UNEXPECTED_END_CODE}"))
@@ -57,11 +181,11 @@ pub fn build_unexpected_end_error(span: Range<usize>, ctx: &str) -> Diagnostic<(
             // format!("`{ch}` is not a ASCII character"),
         ])
         .with_labels(vec![
-            Label::primary((), span).with_message("unexpected end here")
+            Label::primary(span.file, span).with_message("unexpected end here")
         ])
 }
 
-pub fn build_unterminated_string_error(span: Range<usize>) -> Diagnostic<()> {
+pub fn build_unterminated_string_error(span: Span) -> Diagnostic<FileId> {
     Diagnostic::error()
         .with_message("Encountered an unterminated string literal")
         .with_code(format!("E{UNTERMINATED_STRING_CODE}"))
@@ -70,11 +194,250 @@ pub fn build_unterminated_string_error(span: Range<usize>) -> Diagnostic<()> {
                 .to_string(),
         ])
         .with_labels(vec![
-            Label::primary((), span.clone()).with_message("unterminated string literal here")
+            Label::primary(span.file, span).with_message("unterminated string literal here")
+        ])
+}
+
+pub fn build_unterminated_block_comment_error(span: Span) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message("Encountered an unterminated block comment")
+        .with_code(format!("E{UNTERMINATED_BLOCK_COMMENT_CODE}"))
+        .with_notes(vec![
+            "Block comments nest, so every `#[` needs its own matching `]#`".to_string(),
+        ])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("block comment starts here")
+        ])
+}
+
+/// Name and allowed digits of an integer literal radix
+fn describe_radix(radix: u32) -> (&'static str, &'static str) {
+    match radix {
+        2 => ("binary", "`0` and `1`"),
+        8 => ("octal", "`0` to `7`"),
+        16 => ("hexadecimal", "`0` to `9` and `a` to `f`"),
+        _ => ("decimal", "`0` to `9`"),
+    }
+}
+
+pub fn build_invalid_digit_error(span: Span, ch: char, radix: u32) -> Diagnostic<FileId> {
+    let (name, digits) = describe_radix(radix);
+
+    Diagnostic::error()
+        .with_message(format!("Invalid digit `{ch}` in {name} literal"))
+        .with_code(format!("E{INVALID_DIGIT_CODE}"))
+        .with_notes(vec![format!(
+            "Only {digits} are allowed in {name} literals"
+        )])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message(format!("not a valid {name} digit"))
+        ])
+}
+
+pub fn build_empty_radix_literal_error(span: Span, prefix: &str, radix: u32) -> Diagnostic<FileId> {
+    let (name, _) = describe_radix(radix);
+
+    Diagnostic::error()
+        .with_message(format!("Missing digits after `{prefix}`"))
+        .with_code(format!("E{EMPTY_RADIX_LITERAL_CODE}"))
+        .with_notes(vec![format!(
+            "A {name} literal needs at least one digit, eg: `{prefix}0`"
+        )])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("expected digits after this prefix")
+        ])
+}
+
+pub fn build_fractional_radix_literal_error(span: Span, radix: u32) -> Diagnostic<FileId> {
+    let (name, _) = describe_radix(radix);
+
+    Diagnostic::error()
+        .with_message(format!("Fractional part on {name} literal"))
+        .with_code(format!("E{FRACTIONAL_RADIX_LITERAL_CODE}"))
+        .with_notes(vec![format!(
+            "Only decimal literals can be floats, a {name} literal is always an integer"
+        )])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("fractional part here")
+        ])
+}
+
+pub fn build_integer_out_of_range_error(span: Span) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message(format!(
+            "Integer literal out of range for u64, max is {}",
+            u64::MAX
+        ))
+        .with_code(format!("E{INTEGER_OUT_OF_RANGE_CODE}"))
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("integer literal too large")
+        ])
+}
+
+pub fn build_float_out_of_range_error(span: Span) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message(format!(
+            "Float literal out of range for f64, max is {:e}",
+            f64::MAX
+        ))
+        .with_code(format!("E{FLOAT_OUT_OF_RANGE_CODE}"))
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("float literal too large")
+        ])
+}
+
+pub fn build_malformed_float_error(span: Span, literal: &str) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message(format!("Malformed float literal `{literal}`"))
+        .with_code(format!("E{MALFORMED_FLOAT_CODE}"))
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("malformed float literal here")
+        ])
+}
+
+pub fn build_invalid_suffix_error(span: Span, suffix: &str) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message(format!("Invalid suffix `{suffix}` for numeric literal"))
+        .with_code(format!("E{INVALID_SUFFIX_CODE}"))
+        .with_notes(vec![
+            "Valid suffixes are `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`, `f32` and `f64`"
+                .to_string(),
+        ])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("invalid suffix")
+        ])
+}
+
+pub fn build_mismatched_suffix_error(
+    span: Span,
+    suffix: &str,
+    literal: &str,
+) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message(format!(
+            "Suffix `{suffix}` cannot be used on {literal} literals"
+        ))
+        .with_code(format!("E{MISMATCHED_SUFFIX_CODE}"))
+        .with_labels(vec![Label::primary(span.file, span)
+            .with_message(format!("not allowed on {literal} literals"))])
+}
+
+pub fn build_suffix_out_of_range_error(
+    span: Span,
+    suffix: &str,
+    range: &str,
+) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message(format!("Literal out of range for `{suffix}`"))
+        .with_code(format!("E{SUFFIX_OUT_OF_RANGE_CODE}"))
+        .with_notes(vec![format!("`{suffix}` values range from {range}")])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message(format!("does not fit in `{suffix}`"))
+        ])
+}
+
+pub fn build_unterminated_raw_string_error(span: Span, hashes: usize) -> Diagnostic<FileId> {
+    let hashes = "#".repeat(hashes);
+
+    Diagnostic::error()
+        .with_message("Encountered an unterminated raw string literal")
+        .with_code(format!("E{UNTERMINATED_RAW_STRING_CODE}"))
+        .with_notes(vec![format!(
+            "A raw string opened with `r{hashes}\"` must be closed by `\"{hashes}`"
+        )])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("raw string literal starts here")
+        ])
+}
+
+pub fn build_unterminated_multiline_string_error(span: Span) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message("Encountered an unterminated multi-line string literal")
+        .with_code(format!("E{UNTERMINATED_MULTILINE_STRING_CODE}"))
+        .with_notes(vec![
+            "A multi-line string opened with `\"\"\"` must be closed by `\"\"\"`".to_string(),
+        ])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("multi-line string literal starts here")
+        ])
+}
+
+pub fn build_empty_interpolation_error(span: Span) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message("Encountered an empty interpolation in string literal")
+        .with_code(format!("E{EMPTY_INTERPOLATION_CODE}"))
+        .with_notes(vec![
+            "Write an expression between the braces, or escape them as `\\{` and `\\}`".to_string(),
+        ])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("empty interpolation here")
+        ])
+}
+
+pub fn build_unterminated_interpolation_error(span: Span) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message("Encountered an unterminated interpolation in string literal")
+        .with_code(format!("E{UNTERMINATED_INTERPOLATION_CODE}"))
+        .with_notes(vec![
+            "Interpolations must be closed by a `}` on the same line as their string".to_string(),
+        ])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("string literal starts here")
+        ])
+}
+
+pub fn build_unescaped_brace_error(span: Span) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message("Encountered an unescaped `}` in string literal")
+        .with_code(format!("E{UNESCAPED_BRACE_CODE}"))
+        .with_notes(vec![
+            "Braces in string literals delimit interpolations, write `\\}` for a literal `}`"
+                .to_string(),
+        ])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("unescaped `}` here")
+        ])
+}
+
+pub fn build_unterminated_char_error(span: Span) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message("Encountered an unterminated character literal")
+        .with_code(format!("E{UNTERMINATED_CHAR_CODE}"))
+        .with_notes(vec![
+            "Character literals must be closed by a `'` on the same line".to_string(),
+        ])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("unterminated character literal here")
         ])
 }
 
-pub fn build_invalid_escape_error(span: Range<usize>, escape: &str) -> Diagnostic<()> {
+pub fn build_empty_char_error(span: Span) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message("Encountered an empty character literal")
+        .with_code(format!("E{EMPTY_CHAR_CODE}"))
+        .with_notes(vec![
+            "Character literals must hold exactly one character, eg: `'a'` or `'\\n'`".to_string(),
+        ])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("empty character literal here")
+        ])
+}
+
+pub fn build_multiple_characters_error(span: Span, value: &str) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message(format!(
+            "Character literal `{value}` holds more than one character"
+        ))
+        .with_code(format!("E{MULTIPLE_CHARACTERS_CODE}"))
+        .with_notes(vec![format!(
+            "Use a string literal for more than one character: `\"{value}\"`"
+        )])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("more than one character here")
+        ])
+}
+
+pub fn build_invalid_escape_error(span: Span, escape: &str) -> Diagnostic<FileId> {
     Diagnostic::error()
         .with_message(format!(
             "Encountered an invalid escape sequence in string literal `{escape}`"
@@ -82,7 +445,161 @@ pub fn build_invalid_escape_error(span: Range<usize>, escape: &str) -> Diagnosti
         .with_code(format!("E{INVALID_ESCAPE_SEQUENCE_CODE}"))
         .with_notes(vec!["Make sure you use a valid escape sequence".to_string()])
         .with_labels(vec![
-            Label::primary((), span.clone()).with_message("invalid escape sequence here")
+            Label::primary(span.file, span).with_message("invalid escape sequence here")
+        ])
+}
+
+pub fn build_unexpected_token_error(span: Span, expected: &str, found: &str) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message(format!("Expected {expected}, found {found}"))
+        .with_code(format!("E{UNEXPECTED_TOKEN_CODE}"))
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message(format!("expected {expected} here"))
         ])
 }
 
+pub fn build_expected_expression_error(span: Span, found: &str) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message(format!("Expected an expression, found {found}"))
+        .with_code(format!("E{EXPECTED_EXPRESSION_CODE}"))
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("expected expression here")
+        ])
+}
+
+pub fn build_invalid_assignment_target_error(span: Span) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message("Invalid left-hand side of assignment")
+        .with_code(format!("E{INVALID_ASSIGNMENT_TARGET_CODE}"))
+        .with_notes(vec![
+            "Only variables, indexing and member accesses can be assigned to".to_string(),
+        ])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("cannot assign to this expression")
+        ])
+}
+
+pub fn build_non_associative_operator_error(span: Span, op: &str) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message(format!("Operator `{op}` cannot be chained"))
+        .with_code(format!("E{NON_ASSOCIATIVE_OPERATOR_CODE}"))
+        .with_notes(vec![
+            "Use parentheses to make the intended grouping explicit".to_string(),
+        ])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("chained operator here")
+        ])
+}
+
+pub fn build_unattached_doc_comment_warning(span: Span) -> Diagnostic<FileId> {
+    Diagnostic::warning()
+        .with_message("Doc comment is not attached to an item or binding")
+        .with_code(format!("W{UNATTACHED_DOC_COMMENT_CODE}"))
+        .with_notes(vec![
+            "Doc comments document the item, or the local `const` or `var` binding, following them"
+                .to_string(),
+            "Use `#` for a regular comment".to_string(),
+        ])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("this doc comment is ignored")
+        ])
+}
+
+pub fn build_unresolved_module_error(
+    span: Span,
+    module: &str,
+    file_path: &str,
+    reason: &str,
+) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message(format!("Cannot find module `{module}`"))
+        .with_code(format!("E{UNRESOLVED_MODULE_CODE}"))
+        .with_notes(vec![format!(
+            "`{module}` is expected in `{file_path}`: {reason}"
+        )])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("module not found")
+        ])
+}
+
+/// `cycle` lists the modules of the cycle, starting and ending with the same module, and `imports`
+/// the import of each module by the previous one
+pub fn build_import_cycle_error(cycle: &[String], imports: &[Span]) -> Diagnostic<FileId> {
+    let last = imports.len() - 1;
+    let labels = imports
+        .iter()
+        .enumerate()
+        .map(|(index, span)| {
+            let label = if index == last {
+                Label::primary(span.file, *span)
+            } else {
+                Label::secondary(span.file, *span)
+            };
+
+            label.with_message(format!(
+                "`{}` imports `{}` here",
+                cycle[index],
+                cycle[index + 1]
+            ))
+        })
+        .collect();
+
+    Diagnostic::error()
+        .with_message(format!("Import cycle through module `{}`", cycle[0]))
+        .with_code(format!("E{IMPORT_CYCLE_CODE}"))
+        .with_notes(vec![
+            format!("Cycle: {}", cycle.join(" -> ")),
+            "Move the items the modules share into a module they can both import".to_string(),
+        ])
+        .with_labels(labels)
+}
+
+pub fn build_undeclared_name_error(
+    span: Span,
+    name: &str,
+    suggestion: Option<&str>,
+) -> Diagnostic<FileId> {
+    let notes = match suggestion {
+        Some(suggestion) => vec![format!("A similar name exists: `{suggestion}`")],
+        None => Vec::new(),
+    };
+
+    Diagnostic::error()
+        .with_message(format!("Use of undeclared name `{name}`"))
+        .with_code(format!("E{UNDECLARED_NAME_CODE}"))
+        .with_notes(notes)
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message("not found in this scope")
+        ])
+}
+
+pub fn build_duplicate_definition_error(
+    span: Span,
+    previous: Span,
+    name: &str,
+) -> Diagnostic<FileId> {
+    Diagnostic::error()
+        .with_message(format!("`{name}` is defined multiple times"))
+        .with_code(format!("E{DUPLICATE_DEFINITION_CODE}"))
+        .with_notes(vec![format!(
+            "`{name}` can only be defined once in the same scope"
+        )])
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message(format!("`{name}` redefined here")),
+            Label::secondary(previous.file, previous)
+                .with_message(format!("previous definition of `{name}` here")),
+        ])
+}
+
+pub fn build_shadowed_name_warning(span: Span, shadowed: Span, name: &str) -> Diagnostic<FileId> {
+    Diagnostic::warning()
+        .with_message(format!(
+            "`{name}` shadows a declaration of an enclosing scope"
+        ))
+        .with_code(format!("W{SHADOWED_NAME_CODE}"))
+        .with_labels(vec![
+            Label::primary(span.file, span).with_message(format!("`{name}` declared again here")),
+            Label::secondary(shadowed.file, shadowed)
+                .with_message(format!("shadowed declaration of `{name}` here")),
+        ])
+}
@@ -1,20 +1,24 @@
 use codespan_reporting::{
     diagnostic::Diagnostic,
-    files::SimpleFile,
     term::{
         self,
         termcolor::{ColorChoice, StandardStream},
         Chars,
     },
 };
-use colored::Colorize;
 use std::path::{Path, PathBuf};
-use tungsten_symbols::SymbolTable;
+use tungsten_symbols::ScopeTree;
 
 use anyhow::Result;
 use tungsten_utils::guess_host_target_triple;
 
+pub use line_index::*;
+pub use source_map::*;
+pub use tungsten_utils::{FileId, Span};
+
 pub mod error_builders;
+mod line_index;
+mod source_map;
 
 fn get_name(path: &Path) -> Result<&str> {
     let file_name = path.file_name().ok_or_else(|| {
@@ -31,36 +35,43 @@ fn get_name(path: &Path) -> Result<&str> {
 
 #[derive(Debug, Clone)]
 pub struct CompilerContext<'a> {
-    pub symbols: SymbolTable,
-    pub current_scope: Option<SymbolTable>,
+    pub scopes: ScopeTree,
 
-    file: SimpleFile<&'a str, &'a str>,
+    sources: SourceMap<'a>,
+    main_file: FileId,
 
     file_name: &'a str,
     artifact_dir: &'a Path,
-    source_code: &'a str,
-    errors: Vec<Diagnostic<()>>,
+    errors: Vec<Diagnostic<FileId>>,
     /// Architecture
     target_architecture: String,
     optimization_level: u8,
+    /// Whether only ASCII is accepted in identifiers
+    strict_ascii: bool,
+    /// Whether declarations shadowing one of an enclosing scope are reported
+    warn_shadowing: bool,
     file_path: &'a Path,
 }
 
 impl<'a> CompilerContext<'a> {
+    /// Creates a context compiling the main file at `file_path`
     pub fn new(file_path: &'a Path, source_code: &'a str, artifact_dir: &'a Path) -> Self {
         let file_name = get_name(file_path).expect("failed to get name of path");
+        let mut sources = SourceMap::new();
+        let main_file = sources.add_file(file_path, source_code);
 
         Self {
             file_path,
-            file: SimpleFile::new(file_name, source_code),
+            sources,
+            main_file,
             file_name,
-            source_code,
             artifact_dir,
-            current_scope: None,
             target_architecture: guess_host_target_triple(),
-            symbols: SymbolTable::new(None),
+            scopes: ScopeTree::new(),
             errors: Vec::new(),
             optimization_level: 0,
+            strict_ascii: false,
+            warn_shadowing: false,
         }
     }
 
@@ -74,10 +85,55 @@ impl<'a> CompilerContext<'a> {
         self
     }
 
-    pub fn add_error(&mut self, diag: Diagnostic<()>) {
+    /// Restricts identifiers to ASCII, reporting any other character as non-ASCII
+    pub fn set_strict_ascii(&mut self, strict: bool) -> &mut Self {
+        self.strict_ascii = strict;
+        self
+    }
+
+    pub fn is_strict_ascii(&self) -> bool {
+        self.strict_ascii
+    }
+
+    /// Reports declarations shadowing one of an enclosing scope as warnings
+    pub fn set_warn_shadowing(&mut self, warn: bool) -> &mut Self {
+        self.warn_shadowing = warn;
+        self
+    }
+
+    pub fn warns_shadowing(&self) -> bool {
+        self.warn_shadowing
+    }
+
+    /// Registers another source file, eg: an imported module, see [`SourceMap::add_file`]
+    pub fn add_file(
+        &mut self,
+        path: impl Into<PathBuf>,
+        source: impl Into<SourceText<'a>>,
+    ) -> FileId {
+        self.sources.add_file(path, source)
+    }
+
+    pub fn sources(&self) -> &SourceMap<'a> {
+        &self.sources
+    }
+
+    pub fn main_file(&self) -> FileId {
+        self.main_file
+    }
+
+    pub fn add_error(&mut self, diag: Diagnostic<FileId>) {
         self.errors.push(diag);
     }
 
+    pub fn errors(&self) -> &[Diagnostic<FileId>] {
+        &self.errors
+    }
+
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+
     // pub fn add_error(&mut self, err: &str) -> &mut Self {
     //     self.errors.push(err.to_string());
     //     self
@@ -98,12 +154,12 @@ impl<'a> CompilerContext<'a> {
         };
 
         for error in self.errors.clone() {
-            term::emit(&mut writer, &config, &self.file, &error).unwrap();
+            term::emit(&mut writer, &config, &self.sources, &error).unwrap();
         }
     }
 
     pub fn source(&self) -> String {
-        self.source_code.to_string()
+        self.sources.get(self.main_file).source().to_string()
     }
 
     pub fn name(&self) -> String {
@@ -0,0 +1,149 @@
+use std::{collections::BTreeMap, ops::Range};
+
+/// Line start offsets of a source file, computed once so offsets can be mapped to positions and
+/// back in logarithmic time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineIndex {
+    /// Offset of the first byte of every line, starting with `0`
+    line_starts: Vec<usize>,
+    /// Characters longer than one byte, per line index, for converting UTF-16 columns
+    wide_chars: BTreeMap<usize, Vec<WideChar>>,
+    len: usize,
+}
+
+/// 1-based line and column of a position in a source file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineCol {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Unit columns are counted in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColumnEncoding {
+    /// UTF-8 bytes, as used by spans
+    Utf8,
+    /// UTF-16 code units, as used by most editors
+    Utf16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct WideChar {
+    /// Byte offset from the start of the line
+    start: usize,
+    len_utf8: usize,
+    len_utf16: usize,
+}
+
+impl WideChar {
+    /// Number of bytes the character takes up beyond its UTF-16 length
+    fn excess(&self) -> usize {
+        self.len_utf8 - self.len_utf16
+    }
+
+    /// Whether the byte column `column` falls inside the character, past its first byte
+    fn contains(&self, column: usize) -> bool {
+        self.start < column && column < self.start + self.len_utf8
+    }
+}
+
+impl LineIndex {
+    pub fn new(source: &str) -> Self {
+        let mut line_starts = vec![0];
+        let mut wide_chars = BTreeMap::<_, Vec<_>>::new();
+
+        for (offset, ch) in source.char_indices() {
+            if ch == '\n' {
+                line_starts.push(offset + 1);
+            } else if !ch.is_ascii() {
+                let line = line_starts.len() - 1;
+
+                wide_chars.entry(line).or_default().push(WideChar {
+                    start: offset - line_starts[line],
+                    len_utf8: ch.len_utf8(),
+                    len_utf16: ch.len_utf16(),
+                });
+            }
+        }
+
+        Self {
+            line_starts,
+            wide_chars,
+            len: source.len(),
+        }
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Returns the 0-based index of the line containing `offset`. Offsets past the end of the
+    /// file are on the last line.
+    pub fn line(&self, offset: usize) -> usize {
+        self.line_starts.partition_point(|&start| start <= offset) - 1
+    }
+
+    /// Returns the byte range of the 0-based line `line`, including its line terminator
+    pub fn line_range(&self, line: usize) -> Option<Range<usize>> {
+        let start = *self.line_starts.get(line)?;
+        let end = self.line_starts.get(line + 1).copied().unwrap_or(self.len);
+
+        Some(start..end)
+    }
+
+    /// Maps a byte offset to its line and column. Offsets past the end of the file are clamped
+    /// to it, and in UTF-16 offsets inside a character are mapped to the start of the character.
+    pub fn line_col(&self, offset: usize, encoding: ColumnEncoding) -> LineCol {
+        let offset = offset.min(self.len);
+        let line = self.line(offset);
+        let mut column = offset - self.line_starts[line];
+
+        if encoding == ColumnEncoding::Utf16 {
+            let wide_chars = self.wide_chars(line);
+            if let Some(ch) = wide_chars.iter().find(|ch| ch.contains(column)) {
+                column = ch.start;
+            }
+
+            column -= wide_chars
+                .iter()
+                .take_while(|ch| ch.start < column)
+                .map(WideChar::excess)
+                .sum::<usize>();
+        }
+
+        LineCol {
+            line: line + 1,
+            column: column + 1,
+        }
+    }
+
+    /// Maps a line and column back to a byte offset, if it lies within the file and, in UTF-16,
+    /// not between the two halves of a surrogate pair. A column may point one past the end of
+    /// its line.
+    pub fn offset(&self, line_col: LineCol, encoding: ColumnEncoding) -> Option<usize> {
+        let line = line_col.line.checked_sub(1)?;
+        let mut column = line_col.column.checked_sub(1)?;
+        let range = self.line_range(line)?;
+
+        if encoding == ColumnEncoding::Utf16 {
+            for ch in self.wide_chars(line) {
+                if ch.start >= column {
+                    break;
+                }
+                if column < ch.start + ch.len_utf16 {
+                    return None;
+                }
+                column += ch.excess();
+            }
+        }
+
+        let offset = range.start + column;
+        let is_last_line = line + 1 == self.line_count();
+
+        (offset < range.end || (is_last_line && offset == range.end)).then_some(offset)
+    }
+
+    fn wide_chars(&self, line: usize) -> &[WideChar] {
+        self.wide_chars.get(&line).map_or(&[], Vec::as_slice)
+    }
+}
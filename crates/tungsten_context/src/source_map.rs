@@ -0,0 +1,170 @@
+use std::{
+    ops::{Deref, Range},
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use codespan_reporting::files::{Error, Files};
+
+use crate::{FileId, LineIndex};
+
+/// Text of a [`SourceFile`], either borrowed, as the main file's, or shared with the map, as
+/// those read while compiling. Cloning it never copies the text.
+#[derive(Debug, Clone)]
+pub enum SourceText<'a> {
+    Borrowed(&'a str),
+    Shared(Rc<str>),
+}
+
+impl Deref for SourceText<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match self {
+            SourceText::Borrowed(text) => text,
+            SourceText::Shared(text) => text,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SourceText<'a> {
+    fn from(text: &'a str) -> Self {
+        SourceText::Borrowed(text)
+    }
+}
+
+impl From<String> for SourceText<'_> {
+    fn from(text: String) -> Self {
+        SourceText::Shared(text.into())
+    }
+}
+
+/// Source file registered in a [`SourceMap`]
+#[derive(Debug, Clone)]
+pub struct SourceFile<'a> {
+    /// Name diagnostics refer to the file by
+    name: String,
+    path: PathBuf,
+    source: SourceText<'a>,
+    line_index: LineIndex,
+}
+
+impl<'a> SourceFile<'a> {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Handle on the source which does not borrow the map, eg: for tokens to borrow their lexemes
+    /// from while diagnostics are reported to the context owning the map
+    pub fn text(&self) -> SourceText<'a> {
+        self.source.clone()
+    }
+
+    pub fn line_index(&self) -> &LineIndex {
+        &self.line_index
+    }
+}
+
+/// Every source file of a compilation, from the main file to imported, prelude and generated
+/// ones. Diagnostics are reported against it through their [`FileId`].
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap<'a> {
+    files: Vec<SourceFile<'a>>,
+}
+
+impl<'a> SourceMap<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a file, named after `path` in diagnostics. Generated files can use any path
+    /// describing their origin, eg: `<prelude>`.
+    pub fn add_file(
+        &mut self,
+        path: impl Into<PathBuf>,
+        source: impl Into<SourceText<'a>>,
+    ) -> FileId {
+        let path = path.into();
+        let source = source.into();
+        let id = FileId::new(self.files.len());
+
+        self.files.push(SourceFile {
+            name: path.display().to_string(),
+            line_index: LineIndex::new(&source),
+            path,
+            source,
+        });
+
+        id
+    }
+
+    /// Returns the file `id`, which must come from this map
+    pub fn get(&self, id: FileId) -> &SourceFile<'a> {
+        &self.files[id.index()]
+    }
+
+    /// Looks up the file registered with `path`
+    pub fn find(&self, path: &Path) -> Option<FileId> {
+        self.files
+            .iter()
+            .position(|file| file.path == path)
+            .map(FileId::new)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (FileId, &SourceFile<'a>)> {
+        self.files
+            .iter()
+            .enumerate()
+            .map(|(index, file)| (FileId::new(index), file))
+    }
+
+    fn file(&self, id: FileId) -> Result<&SourceFile<'a>, Error> {
+        self.files.get(id.index()).ok_or(Error::FileMissing)
+    }
+}
+
+impl<'a> Files<'a> for SourceMap<'_> {
+    type FileId = FileId;
+    type Name = &'a str;
+    type Source = &'a str;
+
+    fn name(&'a self, id: FileId) -> Result<Self::Name, Error> {
+        Ok(&self.file(id)?.name)
+    }
+
+    fn source(&'a self, id: FileId) -> Result<Self::Source, Error> {
+        Ok(&self.file(id)?.source)
+    }
+
+    fn line_index(&'a self, id: FileId, byte_index: usize) -> Result<usize, Error> {
+        let file = self.file(id)?;
+
+        if byte_index > file.source.len() {
+            return Err(Error::IndexTooLarge {
+                given: byte_index,
+                max: file.source.len(),
+            });
+        }
+
+        Ok(file.line_index.line(byte_index))
+    }
+
+    fn line_range(&'a self, id: FileId, line_index: usize) -> Result<Range<usize>, Error> {
+        let file = self.file(id)?;
+
+        file.line_index
+            .line_range(line_index)
+            .ok_or(Error::LineTooLarge {
+                given: line_index,
+                max: file.line_index.line_count() - 1,
+            })
+    }
+}
@@ -0,0 +1,157 @@
+//! Mapping between byte offsets and line and columns, in UTF-8 and UTF-16.
+
+use tungsten_context::{ColumnEncoding, LineCol, LineIndex};
+
+fn line_col(line: usize, column: usize) -> LineCol {
+    LineCol { line, column }
+}
+
+/// Checks that every character boundary of `source`, including its end, maps to a position which
+/// maps back to it in both encodings
+fn assert_round_trips(source: &str) {
+    let index = LineIndex::new(source);
+    let boundaries = source
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .chain([source.len()]);
+
+    for offset in boundaries {
+        for encoding in [ColumnEncoding::Utf8, ColumnEncoding::Utf16] {
+            let position = index.line_col(offset, encoding);
+
+            assert_eq!(
+                index.offset(position, encoding),
+                Some(offset),
+                "offset {offset} of {source:?} at {position:?} in {encoding:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn ascii_positions() {
+    let index = LineIndex::new("ab\ncd\n\nef");
+
+    assert_eq!(index.line_count(), 4);
+    assert_eq!(index.line_range(0), Some(0..3));
+    assert_eq!(index.line_range(2), Some(6..7));
+    assert_eq!(index.line_range(3), Some(7..9));
+    assert_eq!(index.line_range(4), None);
+
+    let cases = [
+        (0, (1, 1)),
+        (2, (1, 3)),
+        (3, (2, 1)),
+        (6, (3, 1)),
+        (8, (4, 2)),
+    ];
+    for (offset, (line, column)) in cases {
+        for encoding in [ColumnEncoding::Utf8, ColumnEncoding::Utf16] {
+            assert_eq!(index.line_col(offset, encoding), line_col(line, column));
+            assert_eq!(index.offset(line_col(line, column), encoding), Some(offset));
+        }
+    }
+
+    assert_round_trips("ab\ncd\n\nef");
+    assert_round_trips("");
+    assert_round_trips("\n\n");
+}
+
+#[test]
+fn multibyte_characters() {
+    // `é` is 2 bytes and 1 UTF-16 unit, `中` 3 bytes and 1 unit
+    let source = "é中x\nyé";
+    let index = LineIndex::new(source);
+
+    assert_eq!(index.line_col(5, ColumnEncoding::Utf8), line_col(1, 6));
+    assert_eq!(index.line_col(5, ColumnEncoding::Utf16), line_col(1, 3));
+    assert_eq!(index.line_col(8, ColumnEncoding::Utf16), line_col(2, 2));
+    assert_eq!(index.offset(line_col(1, 3), ColumnEncoding::Utf16), Some(5));
+
+    // Offsets inside a character map to its start in UTF-16
+    assert_eq!(index.line_col(1, ColumnEncoding::Utf16), line_col(1, 1));
+    assert_eq!(index.line_col(3, ColumnEncoding::Utf16), line_col(1, 2));
+    assert_eq!(index.line_col(4, ColumnEncoding::Utf16), line_col(1, 2));
+    assert_eq!(index.line_col(9, ColumnEncoding::Utf16), line_col(2, 2));
+    // And keep their byte column in UTF-8
+    assert_eq!(index.line_col(4, ColumnEncoding::Utf8), line_col(1, 5));
+    assert_eq!(index.offset(line_col(1, 5), ColumnEncoding::Utf8), Some(4));
+
+    assert_round_trips(source);
+    assert_round_trips("añb\nçd\n€");
+}
+
+#[test]
+fn surrogate_pairs() {
+    // `😀` is 4 bytes and 2 UTF-16 units
+    let source = "a😀b😀\n😀";
+    let index = LineIndex::new(source);
+
+    assert_eq!(index.line_col(5, ColumnEncoding::Utf16), line_col(1, 4));
+    assert_eq!(index.line_col(6, ColumnEncoding::Utf16), line_col(1, 5));
+    assert_eq!(index.line_col(10, ColumnEncoding::Utf16), line_col(1, 7));
+    assert_eq!(index.line_col(15, ColumnEncoding::Utf16), line_col(2, 3));
+
+    // Inside the character, on either side of the surrogate boundary
+    for offset in 2..5 {
+        assert_eq!(
+            index.line_col(offset, ColumnEncoding::Utf16),
+            line_col(1, 2)
+        );
+    }
+
+    // Between the two halves of a pair is no byte offset
+    assert_eq!(index.offset(line_col(1, 3), ColumnEncoding::Utf16), None);
+    assert_eq!(index.offset(line_col(2, 2), ColumnEncoding::Utf16), None);
+    assert_eq!(index.offset(line_col(1, 4), ColumnEncoding::Utf16), Some(5));
+
+    assert_round_trips(source);
+    assert_round_trips("😀😀\n\u{10FFFF}x");
+}
+
+#[test]
+fn crlf_line_endings() {
+    let source = "ab\r\ncd\r\n";
+    let index = LineIndex::new(source);
+
+    assert_eq!(index.line_count(), 3);
+    assert_eq!(index.line_range(0), Some(0..4));
+    // The `\r` is the last column of its line
+    assert_eq!(index.line_col(2, ColumnEncoding::Utf8), line_col(1, 3));
+    assert_eq!(index.line_col(3, ColumnEncoding::Utf8), line_col(1, 4));
+    assert_eq!(index.line_col(4, ColumnEncoding::Utf8), line_col(2, 1));
+    assert_eq!(index.line_col(8, ColumnEncoding::Utf16), line_col(3, 1));
+
+    assert_round_trips(source);
+    assert_round_trips("é\r\n😀\r\nx");
+}
+
+#[test]
+fn end_of_file() {
+    let source = "ab\ncé";
+    let index = LineIndex::new(source);
+
+    // One past the end of the last line is the end of the file
+    assert_eq!(index.line_col(6, ColumnEncoding::Utf8), line_col(2, 4));
+    assert_eq!(index.line_col(6, ColumnEncoding::Utf16), line_col(2, 3));
+    assert_eq!(index.offset(line_col(2, 4), ColumnEncoding::Utf8), Some(6));
+    assert_eq!(index.offset(line_col(2, 3), ColumnEncoding::Utf16), Some(6));
+
+    // Further offsets are clamped, further positions are outside of the file
+    assert_eq!(index.line_col(100, ColumnEncoding::Utf8), line_col(2, 4));
+    assert_eq!(index.offset(line_col(2, 5), ColumnEncoding::Utf8), None);
+    assert_eq!(index.offset(line_col(3, 1), ColumnEncoding::Utf8), None);
+
+    // Past the end of other lines is the next line, not a position of theirs
+    assert_eq!(index.offset(line_col(1, 3), ColumnEncoding::Utf8), Some(2));
+    assert_eq!(index.offset(line_col(1, 4), ColumnEncoding::Utf8), None);
+
+    // Lines and columns are 1-based
+    assert_eq!(index.offset(line_col(0, 1), ColumnEncoding::Utf8), None);
+    assert_eq!(index.offset(line_col(1, 0), ColumnEncoding::Utf8), None);
+
+    // A trailing line break starts an empty last line
+    let index = LineIndex::new("a\n");
+    assert_eq!(index.line_col(2, ColumnEncoding::Utf16), line_col(2, 1));
+    assert_eq!(index.offset(line_col(2, 1), ColumnEncoding::Utf16), Some(2));
+}
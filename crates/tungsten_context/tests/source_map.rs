@@ -0,0 +1,53 @@
+//! Sources registered in the source map, and diagnostics reported in files other than the main
+//! one rendered through it.
+
+use std::path::Path;
+
+use codespan_reporting::term::{self, termcolor::NoColor, Config};
+use tungsten_context::{error_builders, CompilerContext, Span};
+
+#[test]
+fn diagnostics_point_into_their_own_file() {
+    let main = "import util;\nfunc main() { helper(); }\n";
+    let util = "module util;\n\npub func helper() {\n    missing();\n}\n";
+
+    let mut ctx = CompilerContext::new(Path::new("main.tung"), main, Path::new("target"));
+    let file = ctx.add_file("lib/util.tung", util.to_string());
+
+    let start = util.find("missing").unwrap();
+    let span = Span::new(file, start..start + "missing".len());
+    ctx.add_error(error_builders::build_undeclared_name_error(
+        span, "missing", None,
+    ));
+
+    let mut rendered = NoColor::new(Vec::new());
+    for diagnostic in ctx.errors() {
+        term::emit(&mut rendered, &Config::default(), ctx.sources(), diagnostic).unwrap();
+    }
+    let rendered = String::from_utf8(rendered.into_inner()).unwrap();
+
+    assert!(
+        rendered.contains("lib/util.tung:4:5"),
+        "expected the position in the second file:\n{rendered}"
+    );
+    assert!(rendered.contains("    missing();"), "{rendered}");
+    assert!(!rendered.contains("main.tung"), "{rendered}");
+}
+
+#[test]
+fn text_handles_share_the_source() {
+    let main = "func main() {}";
+    let mut ctx = CompilerContext::new(Path::new("main.tung"), main, Path::new("target"));
+    let file = ctx.add_file("util.tung", "module util;".to_string());
+
+    for (file, source) in [(ctx.main_file(), main), (file, "module util;")] {
+        let text = ctx.sources().get(file).text();
+
+        assert_eq!(&*text, source);
+        assert_eq!(text.as_ptr(), ctx.sources().get(file).source().as_ptr());
+    }
+    assert_eq!(
+        ctx.sources().get(ctx.main_file()).source().as_ptr(),
+        main.as_ptr()
+    );
+}
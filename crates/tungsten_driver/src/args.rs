@@ -25,6 +25,18 @@ pub enum Command {
         /// Path to emit build artifacts
         #[arg(long = "out-dir", default_value = "target")]
         out_dir: PathBuf,
+
+        /// Print the syntax tree of the program
+        #[arg(long = "dump-ast")]
+        dump_ast: bool,
+
+        /// Only accept ASCII identifiers instead of Unicode ones
+        #[arg(long = "strict-ascii")]
+        strict_ascii: bool,
+
+        /// Warn about declarations shadowing one of an enclosing scope
+        #[arg(long = "warn-shadowing")]
+        warn_shadowing: bool,
     },
 }
 
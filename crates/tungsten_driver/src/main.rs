@@ -4,7 +4,8 @@ use anyhow::{bail, Context, Result};
 use args::{get_command, Command};
 use memmap2::Mmap;
 use tungsten_context::CompilerContext;
-use tungsten_lexer::Lexer;
+use tungsten_modules::ModuleLoader;
+use tungsten_resolver::Resolver;
 
 mod args;
 
@@ -29,9 +30,13 @@ fn create_context<'a>(
     source_code: &'a str,
     out_dir: &'a Path,
     opt_level: u8,
+    strict_ascii: bool,
+    warn_shadowing: bool,
 ) -> CompilerContext<'a> {
     let mut ctx = CompilerContext::new(file_path, source_code, out_dir);
-    ctx.set_opt_level(opt_level);
+    ctx.set_opt_level(opt_level)
+        .set_strict_ascii(strict_ascii)
+        .set_warn_shadowing(warn_shadowing);
 
     ctx
 }
@@ -44,6 +49,9 @@ fn main() -> Result<()> {
             file_name,
             opt_level,
             out_dir,
+            dump_ast,
+            strict_ascii,
+            warn_shadowing,
         } => {
             check_path_exists(&file_name, "Input file")?;
             check_path_exists(&out_dir, "Output directory")?;
@@ -58,11 +66,26 @@ fn main() -> Result<()> {
 
             let source = read_file(&file_name).context("failed to read file")?;
 
-            let mut ctx = create_context(&file_name, &source, &out_dir, opt_level);
-            let mut lexer = Lexer::new(&mut ctx, &source);
-            let tokens = lexer.tokenize();
+            let mut ctx = create_context(
+                &file_name,
+                &source,
+                &out_dir,
+                opt_level,
+                strict_ascii,
+                warn_shadowing,
+            );
+            let modules = ModuleLoader::new(&mut ctx).load();
+            Resolver::new(&mut ctx, &modules).resolve();
 
-            println!("{tokens:#?}");
+            ctx.emit_errors();
+
+            if dump_ast {
+                for (_, module) in modules.iter() {
+                    if let Some(program) = &module.program {
+                        println!("{program:#?}");
+                    }
+                }
+            }
         }
     };
 
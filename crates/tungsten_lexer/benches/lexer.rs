@@ -0,0 +1,77 @@
+//! Lexer throughput on a generated multi-megabyte source.
+//!
+//! Compare against another revision by saving a baseline there first:
+//! `cargo bench -p tungsten_lexer -- --save-baseline before`, then
+//! `cargo bench -p tungsten_lexer -- --baseline before` on this one. Both runs must use the same
+//! source template, or the throughputs are not comparable.
+
+use std::{hint::black_box, path::Path};
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use tungsten_context::CompilerContext;
+use tungsten_lexer::Lexer;
+
+/// Target size of the generated source, in bytes
+const SOURCE_SIZE: usize = 4 * 1024 * 1024;
+
+const FUNCTION: &str = r#"
+## Computes a value from its inputs
+pub func compute_{n}(first_value: int, second_value: float) -> float {
+    var total = 0;
+    const limit = 0xFF_FF + {n};  # inline comment
+    for index in 0..=limit {
+        if index % 3 == 0 && first_value > 12_345 {
+            total += index ** 2 << 1;
+        } else {
+            total -= first_value * 1.5e3 / second_value;
+        }
+    }
+    var message = "total of {n}: {total}, escaped \{braces\} and \t tabs\n";
+    var letter = 'x';
+    #[ block #[ nested ]# comment ]#
+    |> total <> message;
+}
+"#;
+
+fn generate_source() -> String {
+    let mut source = String::with_capacity(SOURCE_SIZE + FUNCTION.len());
+
+    for n in 0.. {
+        if source.len() >= SOURCE_SIZE {
+            break;
+        }
+
+        source.push_str(&FUNCTION.replace("{n}", &n.to_string()));
+    }
+
+    source
+}
+
+fn tokenize(source: &str, preserve_trivia: bool) -> usize {
+    let mut ctx = CompilerContext::new(Path::new("bench.tung"), source, Path::new("target"));
+    let file = ctx.main_file();
+    let mut lexer = Lexer::new(&mut ctx, file, source);
+    lexer.set_preserve_trivia(preserve_trivia);
+
+    lexer.count()
+}
+
+fn bench_lexer(c: &mut Criterion) {
+    let source = generate_source();
+
+    let mut group = c.benchmark_group("lexer");
+    group.throughput(Throughput::Bytes(source.len() as u64));
+    group.sample_size(20);
+
+    group.bench_function("tokenize", |b| {
+        b.iter(|| tokenize(black_box(&source), false))
+    });
+    group.bench_function("tokenize_with_trivia", |b| {
+        b.iter(|| tokenize(black_box(&source), true))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_lexer);
+criterion_main!(benches);
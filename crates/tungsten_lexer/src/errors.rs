@@ -1,7 +1,7 @@
-use std::ops::Range;
-
 use thiserror::Error;
 
+use crate::{Confusable, PrimitiveType};
+
 #[derive(Error, Debug)]
 pub enum LexerError {
     #[error("illegal character: `{ch}`, in `{ctx}`")]
@@ -10,9 +10,53 @@ pub enum LexerError {
     #[error("encountered non-ascii character `{0}`")]
     NonAsciiCharacter(char),
 
+    #[error("unexpected character `{0}`")]
+    UnexpectedCharacter(char),
+
+    #[error("character `{ch}` looks like `{}`", confusable.ascii)]
+    ConfusableCharacter { ch: char, confusable: Confusable },
+
+    #[error("identifier `{identifier}` contains `{ch}`, which looks like `{}`", confusable.ascii)]
+    ConfusableIdentifier {
+        identifier: String,
+        ch: char,
+        confusable: Confusable,
+    },
+
+    #[error("invisible character `{name}`")]
+    InvisibleCharacter {
+        ch: char,
+        name: &'static str,
+        in_literal: bool,
+    },
+
     #[error("unterminated string literal")]
     UnterminatedString,
 
+    #[error("unterminated raw string literal")]
+    UnterminatedRawString { hashes: usize },
+
+    #[error("unterminated multi-line string literal")]
+    UnterminatedMultilineString,
+
+    #[error("empty interpolation in string literal")]
+    EmptyInterpolation,
+
+    #[error("unterminated interpolation in string literal")]
+    UnterminatedInterpolation,
+
+    #[error("unescaped `}}` in string literal")]
+    UnescapedBrace,
+
+    #[error("unterminated character literal")]
+    UnterminatedChar,
+
+    #[error("empty character literal")]
+    EmptyChar,
+
+    #[error("character literal `{0}` holds more than one character")]
+    MultipleCharacters(String),
+
     #[error("invalid string escape sequence")]
     InvalidEscape(String),
 
@@ -24,4 +68,40 @@ pub enum LexerError {
 
     #[error("unexpected end in {0}")]
     UnexpectedEnd(&'static str),
+
+    #[error("invalid digit `{ch}` in base {radix} literal")]
+    InvalidDigit { ch: char, radix: u32 },
+
+    #[error("missing digits after `{prefix}`")]
+    EmptyRadixLiteral { prefix: String, radix: u32 },
+
+    #[error("fractional part on base {radix} literal")]
+    FractionalRadixLiteral { radix: u32 },
+
+    #[error("integer literal out of range for u64")]
+    IntegerOutOfRange,
+
+    #[error("float literal out of range for f64")]
+    FloatOutOfRange,
+
+    #[error("malformed float literal `{0}`")]
+    MalformedFloat(String),
+
+    #[error("invalid suffix `{0}` for numeric literal")]
+    InvalidSuffix(String),
+
+    #[error("suffix `{suffix}` cannot be used on {literal} literals")]
+    MismatchedSuffix {
+        suffix: &'static str,
+        literal: &'static str,
+    },
+
+    #[error("literal out of range for `{}`", suffix.as_str())]
+    SuffixOutOfRange {
+        suffix: PrimitiveType,
+        range: String,
+    },
+
+    #[error("unterminated block comment")]
+    UnterminatedBlockComment,
 }
@@ -1,25 +1,16 @@
-use crate::Kind;
-
-pub const KEYWORDS: &[&str] = &[
-    "defer", "func", "do", "break", "continue", "if", "else", "for", "in", "loop", "while",
-    "repeat", "until", "match", "sizeof", "pub", "module", "import", "const", "var",
-];
+use crate::{Kind, PrimitiveType};
 
 pub fn is_keyword(value: &str) -> bool {
-    if value == "|>" {
-        return true;
-    }
-
-    KEYWORDS.contains(&value)
+    str_to_keyword_kind(value).is_some()
 }
 
+/// Looks up the keyword or primitive type keyword spelled `value`
 pub fn str_to_keyword_kind(value: &str) -> Option<Kind> {
     match value {
         "defer" => Some(Kind::DeferKw),
         "func" => Some(Kind::FuncKw),
         "do" => Some(Kind::DoKw),
         "break" => Some(Kind::BreakKw),
-        "|>" => Some(Kind::ReturnKw),
         "continue" => Some(Kind::ContinueKw),
         "if" => Some(Kind::IfKw),
         "else" => Some(Kind::ElseKw),
@@ -36,7 +27,35 @@ pub fn str_to_keyword_kind(value: &str) -> Option<Kind> {
         "import" => Some(Kind::ImportKw),
         "const" => Some(Kind::ConstKw),
         "var" => Some(Kind::VarKw),
+        "void" => Some(Kind::VoidType),
+        "nil" => Some(Kind::NilType),
+        "uint" => Some(Kind::UIntType),
+        "int" => Some(Kind::IntType),
+        "float" => Some(Kind::FloatType),
+        "bool" => Some(Kind::BoolType),
+        "str" => Some(Kind::StrType),
+        "char" => Some(Kind::CharType),
+        "i8" => Some(Kind::I8Type),
+        "i16" => Some(Kind::I16Type),
+        "i32" => Some(Kind::I32Type),
+        "i64" => Some(Kind::I64Type),
+        "u8" => Some(Kind::U8Type),
+        "u16" => Some(Kind::U16Type),
+        "u32" => Some(Kind::U32Type),
+        "u64" => Some(Kind::U64Type),
+        "f32" => Some(Kind::F32Type),
+        "f64" => Some(Kind::F64Type),
 
         _ => None,
     }
 }
+
+/// Returns the sized numeric type a literal suffix names, eg: `u8` in `10u8`
+pub fn suffix_primitive_type(suffix: &str) -> Option<PrimitiveType> {
+    str_to_keyword_kind(suffix)
+        .and_then(Kind::primitive_type)
+        .filter(|primitive| {
+            matches!(primitive, PrimitiveType::F32 | PrimitiveType::F64)
+                || primitive.integer_range().is_some()
+        })
+}
@@ -1,6 +1,9 @@
-#[derive(Debug, PartialEq)]
+use crate::PrimitiveType;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Kind {
     Eof,
+    /// Input that does not form a token, reported by the lexer
     Illegal,
 
     // 1 Character Tokens
@@ -17,7 +20,6 @@ pub enum Kind {
     Bang,
     /// @
     At,
-    Hash,
     Percent,
     Ampersand,
     Slash,
@@ -62,9 +64,9 @@ pub enum Kind {
     DoubleDollar,
     /// ^=
     CaretAssign,
-    /// |=
-    AmpersandAssign,
     /// &=
+    AmpersandAssign,
+    /// |=
     PipeAssign,
     /// +=
     PlusAssign,
@@ -98,6 +100,13 @@ pub enum Kind {
     DoublePeriodAssign,
     // Literals
     StringLiteral,
+    /// Start of an interpolated string, up to its first interpolation: `"text {`
+    StringHead,
+    /// Text between two interpolations: `} text {`
+    StringMiddle,
+    /// End of an interpolated string, after its last interpolation: `} text"`
+    StringTail,
+    CharLiteral,
     BooleanLiteral,
     IntegerLiteral,
     FloatLiteral,
@@ -140,7 +149,73 @@ pub enum Kind {
     BoolType,
     /// str
     StrType,
+    /// char
+    CharType,
+    /// i8
+    I8Type,
+    /// i16
+    I16Type,
+    /// i32
+    I32Type,
+    /// i64
+    I64Type,
+    /// u8
+    U8Type,
+    /// u16
+    U16Type,
+    /// u32
+    U32Type,
+    /// u64
+    U64Type,
+    /// f32
+    F32Type,
+    /// f64
+    F64Type,
 
     /// Identifier
     Identifier,
+
+    /// ## Documentation comment
+    DocComment,
+
+    // Trivia, only emitted by lexers preserving it
+    /// Spaces and tabs
+    Whitespace,
+    /// \n or \r\n
+    Newline,
+    /// # Line comment or #[ block comment ]#
+    Comment,
+}
+
+impl Kind {
+    pub fn is_trivia(self) -> bool {
+        matches!(self, Kind::Whitespace | Kind::Newline | Kind::Comment)
+    }
+
+    /// Returns the primitive type named by a type keyword
+    pub fn primitive_type(self) -> Option<PrimitiveType> {
+        let primitive = match self {
+            Kind::VoidType => PrimitiveType::Void,
+            Kind::NilType => PrimitiveType::Nil,
+            Kind::StrType => PrimitiveType::String,
+            Kind::BoolType => PrimitiveType::Boolean,
+            Kind::CharType => PrimitiveType::Character,
+            Kind::UIntType => PrimitiveType::UnsignedInteger,
+            Kind::IntType => PrimitiveType::SignedInteger,
+            Kind::FloatType => PrimitiveType::Float,
+            Kind::I8Type => PrimitiveType::I8,
+            Kind::I16Type => PrimitiveType::I16,
+            Kind::I32Type => PrimitiveType::I32,
+            Kind::I64Type => PrimitiveType::I64,
+            Kind::U8Type => PrimitiveType::U8,
+            Kind::U16Type => PrimitiveType::U16,
+            Kind::U32Type => PrimitiveType::U32,
+            Kind::U64Type => PrimitiveType::U64,
+            Kind::F32Type => PrimitiveType::F32,
+            Kind::F64Type => PrimitiveType::F64,
+            _ => return None,
+        };
+
+        Some(primitive)
+    }
 }
@@ -0,0 +1,58 @@
+//! Byte classes driving the lexer's fast paths, looked up in a table indexed by byte. Every
+//! class only contains ASCII bytes, so a run of bytes outside a class always ends on a character
+//! boundary.
+
+/// Space and tab
+pub(crate) const WHITESPACE: u8 = 1 << 0;
+/// `\r` and `\n`
+pub(crate) const NEWLINE: u8 = 1 << 1;
+/// `\n`, which ends line comments
+pub(crate) const NEWLINE_LF: u8 = 1 << 5;
+/// ASCII letters, digits and `_`
+pub(crate) const IDENT_CONTINUE: u8 = 1 << 2;
+/// Bytes interrupting the plain text of a string literal: `"`, `\`, `{`, `}`, `\r` and `\n`
+pub(crate) const STRING_SPECIAL: u8 = 1 << 3;
+/// Bytes which may open or close a block comment: `#` and `]`
+pub(crate) const BLOCK_COMMENT_SPECIAL: u8 = 1 << 4;
+
+static CLASSES: [u8; 256] = build_classes();
+
+const fn build_classes() -> [u8; 256] {
+    let mut classes = [0; 256];
+    let mut byte = 0;
+
+    while byte < 128 {
+        let ch = byte as u8 as char;
+        let mut class = 0;
+
+        if matches!(ch, ' ' | '\t') {
+            class |= WHITESPACE;
+        }
+        if matches!(ch, '\r' | '\n') {
+            class |= NEWLINE;
+        }
+        if ch.is_ascii_alphanumeric() || ch == '_' {
+            class |= IDENT_CONTINUE;
+        }
+        if matches!(ch, '"' | '\\' | '{' | '}' | '\r' | '\n') {
+            class |= STRING_SPECIAL;
+        }
+        if ch == '\n' {
+            class |= NEWLINE_LF;
+        }
+        if matches!(ch, '#' | ']') {
+            class |= BLOCK_COMMENT_SPECIAL;
+        }
+
+        classes[byte] = class;
+        byte += 1;
+    }
+
+    classes
+}
+
+/// Whether `byte` belongs to any of the classes in `class`
+#[inline]
+pub(crate) fn is_in(byte: u8, class: u8) -> bool {
+    CLASSES[byte as usize] & class != 0
+}
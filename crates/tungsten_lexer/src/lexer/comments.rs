@@ -0,0 +1,81 @@
+use tungsten_utils::{atom, Atom};
+
+use crate::{errors::LexerError, Kind, Lexer, Value};
+
+use super::{char_class, LexerMode};
+
+impl Lexer<'_, '_, '_> {
+    /// Whether `#` starts a comment here. Inside an interpolation, a comment would run past its
+    /// `}` and the end of the string literal, so `#` is an unexpected character there instead
+    pub(crate) fn are_comments_allowed(&self) -> bool {
+        self.mode() == LexerMode::Normal
+    }
+
+    /// Whether the upcoming `#` starts a `##` doc comment, which is a token rather than trivia
+    pub(crate) fn is_doc_comment_ahead(&self) -> bool {
+        self.rest().starts_with("##")
+    }
+
+    /// Reads a doc comment after its leading `##`, the value being the text with the delimiter and
+    /// one leading space removed
+    pub(crate) fn read_doc_comment(&mut self) -> (Kind, Option<Value>) {
+        if self.peek_byte() == Some(b' ') {
+            self.skip_bytes(1);
+        }
+
+        let start = self.offset();
+        let len = self.count_bytes_not_in(char_class::NEWLINE_LF);
+        self.skip_bytes(len);
+
+        let text = &self.source[start..self.offset()];
+
+        (
+            Kind::DocComment,
+            Some(Value::String(atom!(text.trim_end_matches('\r')))),
+        )
+    }
+
+    /// Skips a comment whose leading `#` has already been consumed, `start` being the offset of
+    /// that `#`
+    ///
+    /// Line comments run from `#` to the end of the line, block comments are delimited by `#[` and
+    /// `]#` and may be nested.
+    pub(crate) fn skip_comment_after_hash(&mut self, start: usize) {
+        if self.peek() != Some('[') {
+            let len = self.count_bytes_not_in(char_class::NEWLINE_LF);
+            self.skip_bytes(len);
+
+            return;
+        }
+
+        self.next_char();
+
+        if let Err(err) = self.skip_block_comment() {
+            self.report_error(err, start..start + 2);
+        }
+    }
+
+    fn skip_block_comment(&mut self) -> Result<(), LexerError> {
+        let mut depth = 1usize;
+
+        while depth > 0 {
+            let len = self.count_bytes_not_in(char_class::BLOCK_COMMENT_SPECIAL);
+            self.skip_bytes(len);
+
+            match self.next_char() {
+                None => return Err(LexerError::UnterminatedBlockComment),
+                Some('#') if self.peek() == Some('[') => {
+                    self.next_char();
+                    depth += 1;
+                }
+                Some(']') if self.peek() == Some('#') => {
+                    self.next_char();
+                    depth -= 1;
+                }
+                Some(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+use std::ops::Range;
+
+use crate::{errors::LexerError, Lexer};
+
+/// Character easily mistaken for the ASCII character `ascii`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Confusable {
+    pub(crate) name: &'static str,
+    pub(crate) ascii: char,
+}
+
+/// Looks up the ASCII character `ch` can be mistaken for, based on the most common homoglyphs of
+/// the Unicode confusables list
+pub(crate) fn confusable(ch: char) -> Option<Confusable> {
+    let (name, ascii) = match ch {
+        // Cyrillic
+        '\u{0430}' => ("Cyrillic small letter a", 'a'),
+        '\u{0435}' => ("Cyrillic small letter ie", 'e'),
+        '\u{043E}' => ("Cyrillic small letter o", 'o'),
+        '\u{0440}' => ("Cyrillic small letter er", 'p'),
+        '\u{0441}' => ("Cyrillic small letter es", 'c'),
+        '\u{0443}' => ("Cyrillic small letter u", 'y'),
+        '\u{0445}' => ("Cyrillic small letter ha", 'x'),
+        '\u{0455}' => ("Cyrillic small letter dze", 's'),
+        '\u{0456}' => ("Cyrillic small letter byelorussian-ukrainian i", 'i'),
+        '\u{0458}' => ("Cyrillic small letter je", 'j'),
+        '\u{0501}' => ("Cyrillic small letter komi de", 'd'),
+        '\u{0410}' => ("Cyrillic capital letter a", 'A'),
+        '\u{0412}' => ("Cyrillic capital letter ve", 'B'),
+        '\u{0415}' => ("Cyrillic capital letter ie", 'E'),
+        '\u{041A}' => ("Cyrillic capital letter ka", 'K'),
+        '\u{041C}' => ("Cyrillic capital letter em", 'M'),
+        '\u{041D}' => ("Cyrillic capital letter en", 'H'),
+        '\u{041E}' => ("Cyrillic capital letter o", 'O'),
+        '\u{0420}' => ("Cyrillic capital letter er", 'P'),
+        '\u{0421}' => ("Cyrillic capital letter es", 'C'),
+        '\u{0422}' => ("Cyrillic capital letter te", 'T'),
+        '\u{0425}' => ("Cyrillic capital letter ha", 'X'),
+        // Greek
+        '\u{03BF}' => ("Greek small letter omicron", 'o'),
+        '\u{03BD}' => ("Greek small letter nu", 'v'),
+        '\u{0391}' => ("Greek capital letter alpha", 'A'),
+        '\u{0392}' => ("Greek capital letter beta", 'B'),
+        '\u{0395}' => ("Greek capital letter epsilon", 'E'),
+        '\u{0396}' => ("Greek capital letter zeta", 'Z'),
+        '\u{0397}' => ("Greek capital letter eta", 'H'),
+        '\u{0399}' => ("Greek capital letter iota", 'I'),
+        '\u{039A}' => ("Greek capital letter kappa", 'K'),
+        '\u{039C}' => ("Greek capital letter mu", 'M'),
+        '\u{039D}' => ("Greek capital letter nu", 'N'),
+        '\u{039F}' => ("Greek capital letter omicron", 'O'),
+        '\u{03A1}' => ("Greek capital letter rho", 'P'),
+        '\u{03A4}' => ("Greek capital letter tau", 'T'),
+        '\u{03A5}' => ("Greek capital letter upsilon", 'Y'),
+        '\u{03A7}' => ("Greek capital letter chi", 'X'),
+        // Punctuation
+        '\u{201C}' => ("left double quotation mark", '"'),
+        '\u{201D}' => ("right double quotation mark", '"'),
+        '\u{201E}' => ("double low-9 quotation mark", '"'),
+        '\u{2018}' => ("left single quotation mark", '\''),
+        '\u{2019}' => ("right single quotation mark", '\''),
+        '\u{201A}' => ("single low-9 quotation mark", '\''),
+        '\u{2010}' => ("hyphen", '-'),
+        '\u{2011}' => ("non-breaking hyphen", '-'),
+        '\u{2013}' => ("en dash", '-'),
+        '\u{2014}' => ("em dash", '-'),
+        '\u{2212}' => ("minus sign", '-'),
+        '\u{2215}' => ("division slash", '/'),
+        '\u{2044}' => ("fraction slash", '/'),
+        '\u{2217}' => ("asterisk operator", '*'),
+        '\u{037E}' => ("Greek question mark", ';'),
+        '\u{2039}' => ("single left-pointing angle quotation mark", '<'),
+        '\u{203A}' => ("single right-pointing angle quotation mark", '>'),
+        '\u{00A0}' => ("no-break space", ' '),
+        // Fullwidth forms
+        '\u{FF01}'..='\u{FF5E}' => (
+            "fullwidth form",
+            char::from_u32(ch as u32 - 0xFF01 + '!' as u32).unwrap(),
+        ),
+        _ => return None,
+    };
+
+    Some(Confusable { name, ascii })
+}
+
+/// Looks up the name of `ch` if it is a zero-width or bidirectional control character, which
+/// render as nothing or reorder the text around them
+pub(crate) fn invisible(ch: char) -> Option<&'static str> {
+    let name = match ch {
+        '\u{200B}' => "zero width space",
+        '\u{200C}' => "zero width non-joiner",
+        '\u{200D}' => "zero width joiner",
+        '\u{2060}' => "word joiner",
+        '\u{FEFF}' => "zero width no-break space",
+        '\u{00AD}' => "soft hyphen",
+        '\u{200E}' => "left-to-right mark",
+        '\u{200F}' => "right-to-left mark",
+        '\u{061C}' => "Arabic letter mark",
+        '\u{202A}' => "left-to-right embedding",
+        '\u{202B}' => "right-to-left embedding",
+        '\u{202C}' => "pop directional formatting",
+        '\u{202D}' => "left-to-right override",
+        '\u{202E}' => "right-to-left override",
+        '\u{2066}' => "left-to-right isolate",
+        '\u{2067}' => "right-to-left isolate",
+        '\u{2068}' => "first strong isolate",
+        '\u{2069}' => "pop directional isolate",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+impl Lexer<'_, '_, '_> {
+    /// Picks the most specific error for a character that cannot start a token
+    pub(crate) fn unexpected_character_error(&self, ch: char) -> LexerError {
+        if let Some(name) = invisible(ch) {
+            LexerError::InvisibleCharacter {
+                ch,
+                name,
+                in_literal: false,
+            }
+        } else if let Some(confusable) = confusable(ch) {
+            LexerError::ConfusableCharacter { ch, confusable }
+        } else if self.context.is_strict_ascii() && !ch.is_ascii() {
+            LexerError::NonAsciiCharacter(ch)
+        } else {
+            LexerError::UnexpectedCharacter(ch)
+        }
+    }
+
+    /// Reports the first character of an identifier which looks like an ASCII letter while the
+    /// rest of it is ASCII, eg: a Cyrillic `а` in `pаssword`
+    pub(crate) fn check_confusable_identifier(&mut self, span: Range<usize>) {
+        let identifier = &self.source[span.clone()];
+
+        if identifier.is_ascii() || !identifier.chars().any(|c| c.is_ascii_alphabetic()) {
+            return;
+        }
+
+        let found = identifier
+            .char_indices()
+            .find_map(|(offset, ch)| Some((offset, ch, confusable(ch)?)));
+
+        if let Some((offset, ch, confusable)) = found {
+            let start = span.start + offset;
+            let err = LexerError::ConfusableIdentifier {
+                identifier: identifier.to_string(),
+                ch,
+                confusable,
+            };
+
+            self.report_error(err, start..start + ch.len_utf8());
+        }
+    }
+
+    /// Reports the invisible characters hidden in the comments or literals of `range`, `in_literal`
+    /// meaning they can be written as escapes instead
+    pub(crate) fn check_invisible_characters(&mut self, range: Range<usize>, in_literal: bool) {
+        let text = &self.source[range.clone()];
+
+        if text.is_ascii() {
+            return;
+        }
+
+        let found: Vec<_> = text
+            .char_indices()
+            .filter_map(|(offset, ch)| Some((range.start + offset, ch, invisible(ch)?)))
+            .collect();
+
+        for (start, ch, name) in found {
+            let err = LexerError::InvisibleCharacter {
+                ch,
+                name,
+                in_literal,
+            };
+
+            self.report_error(err, start..start + ch.len_utf8());
+        }
+    }
+}
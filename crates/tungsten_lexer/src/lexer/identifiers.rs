@@ -0,0 +1,67 @@
+use tungsten_utils::{atom, Atom};
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{str_to_keyword_kind, Kind, Lexer, Value};
+
+use super::{char_class, confusables::invisible};
+
+impl Lexer<'_, '_, '_> {
+    /// Whether `c` starts an identifier: `_` or a letter, which is any `XID_Start` character
+    /// unless the context is in strict ASCII mode
+    pub(crate) fn is_identifier_start(&self, c: char) -> bool {
+        match c {
+            '_' | 'a'..='z' | 'A'..='Z' => true,
+            c if c.is_ascii() => false,
+            c => !self.context.is_strict_ascii() && unicode_ident::is_xid_start(c),
+        }
+    }
+
+    /// Whether `c` continues an identifier, see [`Lexer::is_identifier_start`]
+    pub(crate) fn is_identifier_continue(&self, c: char) -> bool {
+        match c {
+            '_' | 'a'..='z' | 'A'..='Z' | '0'..='9' => true,
+            c if c.is_ascii() || invisible(c).is_some() => false,
+            c => !self.context.is_strict_ascii() && unicode_ident::is_xid_continue(c),
+        }
+    }
+
+    /// Reads a keyword or an identifier, following UAX #31. Identifiers are normalized to NFC so
+    /// that differently encoded spellings name the same thing.
+    pub(crate) fn read_identifier(&mut self, initial_char: char) -> (Kind, Option<Value>) {
+        let start = self.offset() - initial_char.len_utf8();
+
+        loop {
+            let len = self.count_bytes_in(char_class::IDENT_CONTINUE);
+            self.skip_bytes(len);
+
+            // Past the ASCII run, only a non-ASCII character can continue the identifier
+            match self.peek() {
+                Some(c) if !c.is_ascii() && self.is_identifier_continue(c) => {
+                    self.next_char();
+                }
+                _ => break,
+            }
+        }
+
+        let end = self.offset();
+        self.check_confusable_identifier(start..end);
+
+        let text = &self.source[start..end];
+        let normalized: String;
+        let name = if text.is_ascii() {
+            text
+        } else {
+            normalized = text.nfc().collect();
+            &normalized
+        };
+
+        match name {
+            "true" => (Kind::BooleanLiteral, Some(Value::Boolean(true))),
+            "false" => (Kind::BooleanLiteral, Some(Value::Boolean(false))),
+            other => match str_to_keyword_kind(other) {
+                Some(kind) => (kind, kind.primitive_type().map(Value::Primitive)),
+                None => (Kind::Identifier, Some(Value::String(atom!(other)))),
+            },
+        }
+    }
+}
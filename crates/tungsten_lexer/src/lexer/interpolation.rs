@@ -0,0 +1,113 @@
+use crate::{errors::LexerError, Kind, Lexer, Value};
+
+/// What the lexer is currently reading, kept as a stack so interpolated strings can nest
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LexerMode {
+    Normal,
+    /// Inside the `{...}` of an interpolated string literal starting at `string_start`
+    Interpolation {
+        string_start: usize,
+        brace_depth: usize,
+    },
+}
+
+/// How a string literal, or one fragment of it, ended
+pub(crate) enum StringEnd {
+    /// The closing `"`
+    Closed,
+    /// The `{` of an interpolation
+    Interpolation,
+}
+
+impl Lexer<'_, '_, '_> {
+    pub(crate) fn mode(&self) -> LexerMode {
+        self.modes.last().copied().unwrap_or(LexerMode::Normal)
+    }
+
+    /// Whether the next `}` closes the current interpolation rather than a nested block
+    pub(crate) fn is_interpolation_end_ahead(&self) -> bool {
+        matches!(self.mode(), LexerMode::Interpolation { brace_depth: 0, .. })
+    }
+
+    /// Whether the current interpolation was cut off by the end of the line or file, as string
+    /// literals cannot span lines
+    pub(crate) fn is_interpolation_cut_off(&self) -> bool {
+        matches!(self.mode(), LexerMode::Interpolation { .. })
+            && matches!(self.peek(), None | Some('\r') | Some('\n'))
+    }
+
+    /// Keeps track of braces inside an interpolation, so only its own `}` ends it
+    pub(crate) fn track_interpolation_braces(&mut self, kind: Kind) {
+        if let Some(LexerMode::Interpolation { brace_depth, .. }) = self.modes.last_mut() {
+            match kind {
+                Kind::LBrace | Kind::LBraPipe => *brace_depth += 1,
+                Kind::RBrace | Kind::RBraPipe => *brace_depth = brace_depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+    }
+
+    /// Reads a string literal after its opening `"`, yielding a string head if it contains an
+    /// interpolation
+    pub(crate) fn read_string_start(&mut self, string_start: usize) -> (Kind, Option<Value>) {
+        match self.read_string_literal() {
+            Ok(StringEnd::Interpolation) => {
+                self.enter_interpolation(string_start);
+                self.string_fragment(Kind::StringHead)
+            }
+            Ok(StringEnd::Closed) => self.string_fragment(Kind::StringLiteral),
+            Err(err) => {
+                let end = self.offset();
+                self.report_error(err, string_start..end);
+                self.string_fragment(Kind::StringLiteral)
+            }
+        }
+    }
+
+    /// Leaves the current interpolation after its `}` and reads the rest of the string literal
+    pub(crate) fn read_string_continuation(&mut self) -> (Kind, Option<Value>) {
+        let Some(LexerMode::Interpolation { string_start, .. }) = self.modes.pop() else {
+            unreachable!("string continuation outside of an interpolation");
+        };
+
+        self.clear_buffer();
+
+        match self.read_string_literal() {
+            Ok(StringEnd::Interpolation) => {
+                self.enter_interpolation(string_start);
+                self.string_fragment(Kind::StringMiddle)
+            }
+            Ok(StringEnd::Closed) => self.string_fragment(Kind::StringTail),
+            Err(err) => {
+                let end = self.offset();
+                self.report_error(err, string_start..end);
+                self.string_fragment(Kind::StringTail)
+            }
+        }
+    }
+
+    /// Ends an interpolation cut off by the end of the line, yielding an empty string tail so the
+    /// parser can recover
+    pub(crate) fn read_cut_off_interpolation(&mut self) -> (Kind, Option<Value>) {
+        if let Some(LexerMode::Interpolation { string_start, .. }) = self.modes.pop() {
+            self.report_error(
+                LexerError::UnterminatedInterpolation,
+                string_start..string_start + 1,
+            );
+        }
+
+        self.clear_buffer();
+        self.string_fragment(Kind::StringTail)
+    }
+
+    fn enter_interpolation(&mut self, string_start: usize) {
+        self.modes.push(LexerMode::Interpolation {
+            string_start,
+            brace_depth: 0,
+        });
+    }
+
+    fn string_fragment(&mut self, kind: Kind) -> (Kind, Option<Value>) {
+        (kind, Some(Value::String(self.flush_buffer_atom())))
+    }
+}
@@ -1,226 +1,395 @@
-use std::{ops::Range, str::Chars};
+use std::ops::Range;
 
-use tungsten_context::{error_builders, CompilerContext};
+use tungsten_context::{error_builders, CompilerContext, FileId, Span};
 use tungsten_utils::{atom, Atom};
 
+mod char_class;
+mod comments;
+mod confusables;
+mod identifiers;
+mod interpolation;
 mod numbers;
 mod strings;
 
-use crate::{
-    errors::LexerError, is_keyword, numeric_result::NumericResult, str_to_keyword_kind, Kind,
-    Position, Token, Value,
-};
+pub(crate) use confusables::Confusable;
+use interpolation::LexerMode;
+
+use crate::{errors::LexerError, numeric_result::NumericResult, Kind, Position, Token, Value};
 
 #[derive(Debug)]
-pub struct Lexer<'a> {
-    pub(crate) context: &'a mut CompilerContext<'a>,
-    pub(crate) source: &'a str,
-    pub(crate) chars: Chars<'a>,
+pub struct Lexer<'a, 'ctx, 'src> {
+    pub(crate) context: &'a mut CompilerContext<'ctx>,
+    pub(crate) file: FileId,
+    pub(crate) source: &'src str,
+    /// Byte offset of the next character to read, always on a character boundary
+    pub(crate) cursor: usize,
+    /// 0-based line of the last token read, tokens only ever start further into the file
+    pub(crate) line: usize,
     pub(crate) buffer: String,
+    pub(crate) modes: Vec<LexerMode>,
+    /// Whether the `Kind::Eof` token was read, ending the iteration
+    pub(crate) finished: bool,
+    /// Whether whitespace and comments are emitted as trivia tokens rather than skipped
+    pub(crate) preserve_trivia: bool,
 }
 
-impl<'a> Lexer<'a> {
-    pub fn new(context: &'a mut CompilerContext<'a>, source: &'a str) -> Self {
+impl<'a, 'ctx, 'src> Lexer<'a, 'ctx, 'src> {
+    /// Creates a lexer over `source`, the source of `file`, one of the context's source files.
+    /// Tokens borrow their lexemes from `source`, so they can outlive the borrow of the context.
+    pub fn new(context: &'a mut CompilerContext<'ctx>, file: FileId, source: &'src str) -> Self {
+        debug_assert_eq!(
+            source,
+            context.sources().get(file).source(),
+            "lexed source differs from the file's"
+        );
+
         Self {
-            chars: source.chars(),
+            cursor: 0,
+            line: 0,
             buffer: String::new(),
+            modes: vec![LexerMode::Normal],
+            finished: false,
+            preserve_trivia: false,
             context,
+            file,
             source,
         }
     }
 
+    /// Emits whitespace, newlines and comments as trivia tokens, so that the lexemes of all tokens
+    /// add up to the source. The parser expects trivia to be filtered out.
+    pub fn set_preserve_trivia(&mut self, preserve: bool) -> &mut Self {
+        self.preserve_trivia = preserve;
+        self
+    }
+
     pub fn is_at_eof(&self) -> bool {
-        self.chars.as_str().is_empty()
+        self.cursor >= self.source.len()
     }
 
-    pub fn tokenize(&mut self) -> Vec<Token> {
-        let mut tokens = Vec::new();
+    /// Reads all remaining tokens, ending with a single `Kind::Eof` token
+    pub fn tokenize(&mut self) -> Vec<Token<'src>> {
+        self.collect()
+    }
 
-        while !self.is_at_eof() {
-            let token = self.read_next();
-            tokens.push(token);
+    pub fn read_next(&mut self) -> Token<'src> {
+        let trivia_start = self.offset();
+        if !self.preserve_trivia {
+            self.skip_trivia();
         }
 
-        self.context.emit_errors();
-
-        tokens
-    }
-
-    pub fn read_next(&mut self) -> Token {
         let start = self.offset();
-        let (kind, value) = self.read_next_kind();
+        let (kind, value) = if self.is_interpolation_cut_off() {
+            self.read_cut_off_interpolation()
+        } else if let Some(kind) = self.read_trivia() {
+            (kind, None)
+        } else {
+            self.read_next_kind()
+        };
         let end = self.offset();
 
-        let trimmed = &self.source[start..end].trim_start_matches(char::is_whitespace);
-        let len = self.source[start..end].len() - trimmed.len();
-
-        // Column offset computation causes first line to have incorrect column number (starting at 0,
-        // instead of 1)
-        let (line, mut column) = self.calculate_line_column(start + len);
-        if line == 1 {
-            column += 1;
+        self.track_interpolation_braces(kind);
+
+        // Invisible characters in code are reported as unexpected characters instead
+        self.check_invisible_characters(trivia_start..start, false);
+        match kind {
+            Kind::StringLiteral
+            | Kind::StringHead
+            | Kind::StringMiddle
+            | Kind::StringTail
+            | Kind::CharLiteral => {
+                // Raw strings take `\u{...}` verbatim, so the character can only be removed
+                let escapable = !self.source[start..end].starts_with('r');
+                self.check_invisible_characters(start..end, escapable);
+            }
+            Kind::Comment | Kind::DocComment => self.check_invisible_characters(start..end, false),
+            _ => {}
         }
 
         Token {
-            span: start..end,
-            position: Position { line, column },
-            lexeme: atom!(self.source[start..end].trim_start()),
+            span: Span::new(self.file, start..end),
+            position: self.position_of(start),
+            lexeme: &self.source[start..end],
             kind,
             value,
         }
     }
 
-    fn calculate_line_column(&self, start_offset: usize) -> (usize, usize) {
-        let line = &self.source[..start_offset].matches('\n').count() + 1;
-        let last_newline_index = &self.source[..start_offset].rfind('\n').unwrap_or(0);
-        let column = start_offset - last_newline_index;
+    /// Line and column of `offset`, which lies past the previous token. Rather than looking the
+    /// line up for every token, it is moved forward from the one of the previous token.
+    fn position_of(&mut self, offset: usize) -> Position {
+        let line_index = self.context.sources().get(self.file).line_index();
 
-        (line, column)
+        while line_index
+            .line_range(self.line + 1)
+            .is_some_and(|next| next.start <= offset)
+        {
+            self.line += 1;
+        }
+
+        let line_start = line_index
+            .line_range(self.line)
+            .map_or(0, |line| line.start);
+
+        Position {
+            line: self.line + 1,
+            column: offset - line_start + 1,
+        }
+    }
+
+    /// Skips whitespace and comments preceding the next token
+    fn skip_trivia(&mut self) {
+        // Interpolations end with the line, see `is_interpolation_cut_off`
+        let whitespace = match self.mode() {
+            LexerMode::Normal => char_class::WHITESPACE | char_class::NEWLINE,
+            LexerMode::Interpolation { .. } => char_class::WHITESPACE,
+        };
+
+        loop {
+            let len = self.count_bytes_in(whitespace);
+            if len > 0 {
+                self.skip_bytes(len);
+                continue;
+            }
+
+            match self.peek_byte() {
+                Some(b'#') if self.are_comments_allowed() && !self.is_doc_comment_ahead() => {
+                    let start = self.offset();
+                    self.skip_bytes(1);
+                    self.skip_comment_after_hash(start);
+                }
+                _ => break,
+            }
+        }
+    }
+
+    /// Reads a single trivia token when preserving trivia
+    fn read_trivia(&mut self) -> Option<Kind> {
+        if !self.preserve_trivia {
+            return None;
+        }
+
+        match self.peek_byte()? {
+            b'\n' => {
+                self.skip_bytes(1);
+                Some(Kind::Newline)
+            }
+            b'\r' if self.rest().starts_with("\r\n") => {
+                self.skip_bytes(2);
+                Some(Kind::Newline)
+            }
+            b' ' | b'\t' | b'\r' => {
+                loop {
+                    let len = self.count_bytes_in(char_class::WHITESPACE);
+                    self.skip_bytes(len);
+
+                    // A lone `\r` is whitespace, while `\r\n` is the next newline
+                    if self.peek_byte() != Some(b'\r') || self.rest().starts_with("\r\n") {
+                        break;
+                    }
+                    self.skip_bytes(1);
+                }
+                Some(Kind::Whitespace)
+            }
+            b'#' if self.are_comments_allowed() && !self.is_doc_comment_ahead() => {
+                let start = self.offset();
+                self.skip_bytes(1);
+                self.skip_comment_after_hash(start);
+                Some(Kind::Comment)
+            }
+            _ => None,
+        }
     }
 
     pub(crate) fn offset(&self) -> usize {
-        self.source.len() - self.chars.as_str().len()
+        self.cursor
+    }
+
+    /// The input left to read
+    pub(crate) fn rest(&self) -> &'src str {
+        &self.source[self.cursor..]
+    }
+
+    /// Counts the leading bytes of the remaining input belonging to `class`
+    pub(crate) fn count_bytes_in(&self, class: u8) -> usize {
+        let bytes = self.rest().as_bytes();
+
+        bytes
+            .iter()
+            .position(|&byte| !char_class::is_in(byte, class))
+            .unwrap_or(bytes.len())
+    }
+
+    /// Counts the leading bytes of the remaining input not belonging to `class`
+    pub(crate) fn count_bytes_not_in(&self, class: u8) -> usize {
+        let bytes = self.rest().as_bytes();
+
+        bytes
+            .iter()
+            .position(|&byte| char_class::is_in(byte, class))
+            .unwrap_or(bytes.len())
+    }
+
+    /// Skips `len` bytes, as counted by [`Lexer::count_bytes_in`] or [`Lexer::count_bytes_not_in`]
+    /// so they end on a character boundary
+    pub(crate) fn skip_bytes(&mut self, len: usize) {
+        self.cursor += len;
     }
 
     pub(crate) fn read_next_kind(&mut self) -> (Kind, Option<Value>) {
-        while let Some(c) = self.chars.next() {
-            match c {
+        if let Some(byte) = self.peek_byte() {
+            // Only identifiers start with a non-ASCII character, which is decoded just for them
+            if !byte.is_ascii() {
+                let c = self.next_char().unwrap();
+
+                if self.is_identifier_start(c) {
+                    return self.read_identifier(c);
+                }
+
+                return self.read_illegal_character(c);
+            }
+
+            self.skip_bytes(1);
+
+            match byte {
                 // Lookahead(0) Tokens
-                ',' => return (Kind::Comma, None),
-                ';' => return (Kind::Semicolon, None),
-                '@' => return (Kind::At, None),
-                '#' => return (Kind::Hash, None),
-                '[' => return (Kind::LBracket, None),
-                ']' => return (Kind::RBracket, None),
-                ')' => return (Kind::RParen, None),
-                '}' => return (Kind::RBrace, None),
-                '?' => return (Kind::Question, None),
-                '~' => return (Kind::Tilde, None),
+                b',' => return (Kind::Comma, None),
+                b';' => return (Kind::Semicolon, None),
+                b'@' => return (Kind::At, None),
+                b'[' => return (Kind::LBracket, None),
+                b']' => return (Kind::RBracket, None),
+                b')' => return (Kind::RParen, None),
+                b'}' if self.is_interpolation_end_ahead() => {
+                    return self.read_string_continuation();
+                }
+                b'}' => return (Kind::RBrace, None),
+                b'?' => return (Kind::Question, None),
+                b'~' => return (Kind::Tilde, None),
                 // Lookahead(1) Tokens
-                '$' => match self.peek() {
-                    Some('$') => {
-                        self.chars.next();
+                b'$' => match self.peek_byte() {
+                    Some(b'$') => {
+                        self.skip_bytes(1);
                         return (Kind::DoubleDollar, None);
                     }
-                    _ => panic!("Illegal character '$'"),
+                    _ => return self.read_illegal_character('$'),
                 },
-                ':' => match self.peek() {
-                    Some(':') => {
-                        self.chars.next();
+                b':' => match self.peek_byte() {
+                    Some(b':') => {
+                        self.skip_bytes(1);
                         return (Kind::DoubleColon, None);
                     }
                     _ => return (Kind::Colon, None),
                 },
-                '^' => match self.peek() {
-                    Some('=') => {
-                        self.chars.next();
+                b'^' => match self.peek_byte() {
+                    Some(b'=') => {
+                        self.skip_bytes(1);
                         return (Kind::CaretAssign, None);
                     }
                     _ => return (Kind::Caret, None),
                 },
-                '+' => match self.peek() {
-                    Some('+') => {
-                        self.chars.next();
+                b'+' => match self.peek_byte() {
+                    Some(b'+') => {
+                        self.skip_bytes(1);
                         return (Kind::DoublePlus, None);
                     }
-                    Some('=') => {
-                        self.chars.next();
+                    Some(b'=') => {
+                        self.skip_bytes(1);
                         return (Kind::PlusAssign, None);
                     }
                     _ => return (Kind::Plus, None),
                 },
-                '-' => match self.peek() {
-                    Some('>') => {
-                        self.chars.next();
+                b'-' => match self.peek_byte() {
+                    Some(b'>') => {
+                        self.skip_bytes(1);
                         return (Kind::Arrow, None);
                     }
-                    Some('-') => {
-                        self.chars.next();
+                    Some(b'-') => {
+                        self.skip_bytes(1);
                         return (Kind::DoubleDash, None);
                     }
-                    Some('=') => {
-                        self.chars.next();
+                    Some(b'=') => {
+                        self.skip_bytes(1);
                         return (Kind::DashAssign, None);
                     }
                     _ => return (Kind::Dash, None),
                 },
-                '%' => match self.peek() {
-                    Some('=') => {
-                        self.chars.next();
+                b'%' => match self.peek_byte() {
+                    Some(b'=') => {
+                        self.skip_bytes(1);
                         return (Kind::PercentAssign, None);
                     }
                     _ => return (Kind::Percent, None),
                 },
-                '!' => match self.peek() {
+                b'!' => match self.peek_byte() {
                     // !=
-                    Some('=') => {
-                        self.chars.next();
+                    Some(b'=') => {
+                        self.skip_bytes(1);
 
                         return (Kind::BangEqual, None);
                     }
                     _ => return (Kind::Bang, None),
                 },
-                '(' => match self.peek() {
+                b'(' => match self.peek_byte() {
                     // (|
-                    Some('|') => {
-                        self.chars.next();
+                    Some(b'|') => {
+                        self.skip_bytes(1);
 
                         return (Kind::LParPipe, None);
                     }
                     _ => return (Kind::LParen, None),
                 },
-                '{' => match self.peek() {
+                b'{' => match self.peek_byte() {
                     // (|
-                    Some('|') => {
-                        self.chars.next();
+                    Some(b'|') => {
+                        self.skip_bytes(1);
 
                         return (Kind::LBraPipe, None);
                     }
                     _ => return (Kind::LBrace, None),
                 },
-                '&' => match self.peek() {
+                b'&' => match self.peek_byte() {
                     // ||
-                    Some('&') => {
-                        self.chars.next();
+                    Some(b'&') => {
+                        self.skip_bytes(1);
 
                         return (Kind::DoubleAmpersand, None);
                     }
                     // |)
-                    Some('=') => {
-                        self.chars.next();
+                    Some(b'=') => {
+                        self.skip_bytes(1);
 
                         return (Kind::AmpersandAssign, None);
                     }
                     _ => return (Kind::Ampersand, None),
                 },
-                '|' => match self.peek() {
+                b'|' => match self.peek_byte() {
                     // ||
-                    Some('|') => {
-                        self.chars.next();
+                    Some(b'|') => {
+                        self.skip_bytes(1);
 
                         return (Kind::DoublePipe, None);
                     }
                     // |>
-                    Some('>') => {
-                        self.chars.next();
+                    Some(b'>') => {
+                        self.skip_bytes(1);
 
                         return (Kind::ReturnKw, None);
                     }
                     // |)
-                    Some(')') => {
-                        self.chars.next();
+                    Some(b')') => {
+                        self.skip_bytes(1);
 
                         return (Kind::RParPipe, None);
                     }
-                    // |}
-                    Some('}') => {
-                        self.chars.next();
+                    // |}, unless the `}` ends the current interpolation
+                    Some(b'}') if !self.is_interpolation_end_ahead() => {
+                        self.skip_bytes(1);
 
                         return (Kind::RBraPipe, None);
                     }
                     // |=
-                    Some('=') => {
-                        self.chars.next();
+                    Some(b'=') => {
+                        self.skip_bytes(1);
 
                         return (Kind::PipeAssign, None);
                     }
@@ -228,21 +397,21 @@ impl<'a> Lexer<'a> {
                 },
 
                 // Lookahead(2) Tokens
-                '*' => match self.peek() {
+                b'*' => match self.peek_byte() {
                     // *=
-                    Some('=') => {
-                        self.chars.next();
+                    Some(b'=') => {
+                        self.skip_bytes(1);
 
                         return (Kind::AsteriskAssign, None);
                     }
                     // **
-                    Some('*') => {
-                        self.chars.next();
+                    Some(b'*') => {
+                        self.skip_bytes(1);
 
-                        match self.peek() {
+                        match self.peek_byte() {
                             // **=
-                            Some('=') => {
-                                self.chars.next();
+                            Some(b'=') => {
+                                self.skip_bytes(1);
                                 return (Kind::DoubleAsteriskAssign, None);
                             }
                             // **
@@ -251,21 +420,21 @@ impl<'a> Lexer<'a> {
                     }
                     _ => return (Kind::Asterisk, None),
                 },
-                '/' => match self.peek() {
+                b'/' => match self.peek_byte() {
                     // /=
-                    Some('=') => {
-                        self.chars.next();
+                    Some(b'=') => {
+                        self.skip_bytes(1);
 
                         return (Kind::SlashAssign, None);
                     }
                     // //
-                    Some('/') => {
-                        self.chars.next();
+                    Some(b'/') => {
+                        self.skip_bytes(1);
 
-                        match self.peek() {
+                        match self.peek_byte() {
                             // //=
-                            Some('=') => {
-                                self.chars.next();
+                            Some(b'=') => {
+                                self.skip_bytes(1);
                                 return (Kind::DoubleSlashAssign, None);
                             }
                             // //
@@ -274,66 +443,66 @@ impl<'a> Lexer<'a> {
                     }
                     _ => return (Kind::Slash, None),
                 },
-                '>' => match self.peek() {
+                b'>' => match self.peek_byte() {
                     // >>
-                    Some('>') => {
-                        self.chars.next();
+                    Some(b'>') => {
+                        self.skip_bytes(1);
 
-                        match self.peek() {
+                        match self.peek_byte() {
                             // >>=
-                            Some('=') => {
-                                self.chars.next();
+                            Some(b'=') => {
+                                self.skip_bytes(1);
                                 return (Kind::DoubleGreaterAssign, None);
                             }
                             _ => return (Kind::DoubleGreater, None),
                         }
                     }
                     // >=
-                    Some('=') => {
-                        self.chars.next();
+                    Some(b'=') => {
+                        self.skip_bytes(1);
 
                         return (Kind::GreaterEq, None);
                     }
                     _ => return (Kind::Greater, None),
                 },
-                '=' => match self.peek() {
+                b'=' => match self.peek_byte() {
                     // =>
-                    Some('>') => {
-                        self.chars.next();
+                    Some(b'>') => {
+                        self.skip_bytes(1);
 
                         return (Kind::FatArrow, None);
                     }
                     // ==
-                    Some('=') => {
-                        self.chars.next();
+                    Some(b'=') => {
+                        self.skip_bytes(1);
 
                         return (Kind::DoubleEqual, None);
                     }
                     _ => return (Kind::Equal, None),
                 },
-                '<' => match self.peek() {
+                b'<' => match self.peek_byte() {
                     // <<
-                    Some('<') => {
-                        self.chars.next();
+                    Some(b'<') => {
+                        self.skip_bytes(1);
 
-                        match self.peek() {
+                        match self.peek_byte() {
                             // <<=
-                            Some('=') => {
-                                self.chars.next();
+                            Some(b'=') => {
+                                self.skip_bytes(1);
                                 return (Kind::DoubleLessAssign, None);
                             }
                             _ => return (Kind::DoubleLess, None),
                         }
                     }
                     // <>
-                    Some('>') => {
-                        self.chars.next();
+                    Some(b'>') => {
+                        self.skip_bytes(1);
 
                         return (Kind::LessGreater, None);
                     }
                     // <=
-                    Some('=') => {
-                        self.chars.next();
+                    Some(b'=') => {
+                        self.skip_bytes(1);
 
                         return (Kind::LessEq, None);
                     }
@@ -342,26 +511,26 @@ impl<'a> Lexer<'a> {
                 // Literals
                 // Number Literals
                 // Eg: .345 .892858
-                '.' => match self.peek() {
+                b'.' => match self.peek_byte() {
                     // ..
-                    Some('.') => {
-                        self.chars.next();
+                    Some(b'.') => {
+                        self.skip_bytes(1);
 
-                        match self.peek() {
+                        match self.peek_byte() {
                             // ...
-                            Some('.') => {
-                                self.chars.next();
+                            Some(b'.') => {
+                                self.skip_bytes(1);
                                 return (Kind::Ellipsis, None);
                             }
                             // ..=
-                            Some('=') => {
-                                self.chars.next();
+                            Some(b'=') => {
+                                self.skip_bytes(1);
                                 return (Kind::DoublePeriodAssign, None);
                             }
                             _ => return (Kind::DoublePeriod, None),
                         }
                     }
-                    Some('0'..='9') => {
+                    Some(b'0'..=b'9') => {
                         self.clear_buffer();
                         self.buffer.push('.'); // Push the initial period
 
@@ -369,125 +538,197 @@ impl<'a> Lexer<'a> {
                         if let Err(err) = self.read_float_after_decimal_point() {
                             let end = self.offset();
                             self.report_error(err, start..end);
-                        }
+                            self.clear_buffer();
 
-                        let value: f64 = self.flush_buffer().parse().expect("Could not parse f64");
+                            return (Kind::FloatLiteral, Some(Value::Float(0.0, None)));
+                        }
 
-                        return (Kind::FloatLiteral, Some(Value::Float(value)));
+                        return self.finish_numeric_literal(NumericResult::Float, start - 1);
                     }
                     _ => return (Kind::Period, None),
                 },
                 // Read Zero, or Float
-                '0' => {
+                b'0' => {
                     self.clear_buffer();
                     self.buffer.push('0'); // Push the initial zero
 
                     let start = self.offset();
-                    match self.read_numeric_literal_starting_with_zero() {
-                        Err(err) => {
-                            let end = self.offset();
-                            self.report_error(err, start..end);
-                        }
+                    return match self.read_numeric_literal_starting_with_zero() {
                         Ok(numeric_result) => {
-                            let value = self.flush_buffer();
-
-                            return match numeric_result {
-                                NumericResult::Integer => {
-                                    let value =
-                                        value.parse::<u64>().expect("Could not parse to u64");
-
-                                    (Kind::IntegerLiteral, Some(Value::Integer(value)))
-                                }
-                                NumericResult::Float => {
-                                    let value =
-                                        value.parse::<f64>().expect("Could not parse to f64");
-
-                                    (Kind::FloatLiteral, Some(Value::Float(value)))
-                                }
-                            };
+                            self.finish_numeric_literal(numeric_result, start - 1)
                         }
-                    }
+                        Err(err) => self.read_illegal_numeric_literal(err, start - 1),
+                    };
                 }
                 // Read number
-                '1'..='9' => {
+                b'1'..=b'9' => {
                     self.clear_buffer();
-                    self.buffer.push(c); // Push the initial digit
+                    self.buffer.push(byte as char); // Push the initial digit
 
                     let start = self.offset();
-                    match self.read_decimal_literal_after_first_digit() {
-                        Err(err) => {
-                            let end = self.offset();
-                            self.report_error(err, start..end);
-                        }
+                    return match self.read_decimal_literal_after_first_digit() {
                         Ok(numeric_result) => {
-                            let value = self.flush_buffer();
-
-                            return match numeric_result {
-                                NumericResult::Integer => {
-                                    let value =
-                                        value.parse::<u64>().expect("Could not parse to u64");
-
-                                    (Kind::IntegerLiteral, Some(Value::Integer(value)))
-                                }
-                                NumericResult::Float => {
-                                    let value =
-                                        value.parse::<f64>().expect("Could not parse to f64");
-
-                                    (Kind::FloatLiteral, Some(Value::Float(value)))
-                                }
-                            };
+                            self.finish_numeric_literal(numeric_result, start - 1)
                         }
-                    }
+                        Err(err) => self.read_illegal_numeric_literal(err, start - 1),
+                    };
                 }
                 // Read String
-                '"' => {
+                b'"' => {
                     self.clear_buffer();
 
                     let start = self.offset();
-                    if let Err(err) = self.read_string_literal() {
-                        let end = self.offset();
-                        self.report_error(err, start..end);
+                    if !self.rest().starts_with("\"\"") {
+                        return self.read_string_start(start - 1);
+                    }
+
+                    self.skip_bytes(2);
+                    if let Err(err) = self.read_multiline_string_literal() {
+                        let span = match err {
+                            // Point at the opening `"""` rather than the end of the file
+                            LexerError::UnterminatedMultilineString => start - 1..start + 2,
+                            _ => start..self.offset(),
+                        };
+                        self.report_error(err, span);
+                    }
+
+                    return (
+                        Kind::StringLiteral,
+                        Some(Value::String(self.flush_buffer_atom())),
+                    );
+                }
+                // Read Raw String, eg: r"..." or r#"..."#
+                b'r' if self.is_raw_string_ahead() => {
+                    self.clear_buffer();
+
+                    let start = self.offset() - 1;
+                    if let Err(err) = self.read_raw_string_literal() {
+                        let span = match err {
+                            // Point at the opening `r#"` rather than the end of the file
+                            LexerError::UnterminatedRawString { hashes } => {
+                                start..start + hashes + 2
+                            }
+                            _ => start..self.offset(),
+                        };
+                        self.report_error(err, span);
                     }
 
-                    let value = self.flush_buffer();
+                    return (
+                        Kind::StringLiteral,
+                        Some(Value::String(self.flush_buffer_atom())),
+                    );
+                }
+                // Read Character
+                b'\'' => {
+                    self.clear_buffer();
+
+                    let start = self.offset() - 1;
+                    let value = match self.read_char_literal() {
+                        Ok(c) => c,
+                        Err(err) => {
+                            let end = self.offset();
+                            self.report_error(err, start..end);
 
-                    return (Kind::StringLiteral, Some(Value::String(atom!(value))));
+                            char::REPLACEMENT_CHARACTER
+                        }
+                    };
+                    self.clear_buffer();
+
+                    return (Kind::CharLiteral, Some(Value::Character(value)));
                 }
-                '_' | 'a'..='z' | 'A'..='Z' => return self.read_identifier(c),
-                ' ' | '\t' | '\r' | '\n' => {}
-                // TODO COMMENTS!
-                ch => {
-                    let span = self.offset() - 2..self.offset();
-                    // println!(
-                    //     "Unknown: `{}` `{}` EHeh",
-                    //     self.offset(),
-                    //     &self.source[self.offset() - 2..self.offset()]
-                    // );
-                    self.report_error(LexerError::NonAsciiCharacter(ch), span);
+                b'_' | b'a'..=b'z' | b'A'..=b'Z' => return self.read_identifier(byte as char),
+                // Whitespace and other comments are trivia, see `skip_trivia` and `read_trivia`
+                b'#' if self.are_comments_allowed() && self.peek_byte() == Some(b'#') => {
+                    self.skip_bytes(1);
+                    return self.read_doc_comment();
                 }
+                _ => return self.read_illegal_character(byte as char),
             }
         }
         (Kind::Eof, None)
     }
 
+    /// Reports a character which cannot start a token, yielding it as an illegal token the parser
+    /// skips
+    fn read_illegal_character(&mut self, ch: char) -> (Kind, Option<Value>) {
+        let span = self.offset() - ch.len_utf8()..self.offset();
+        let err = self.unexpected_character_error(ch);
+        self.report_error(err, span);
+
+        (Kind::Illegal, None)
+    }
+
+    /// Reports a malformed numeric literal starting at `start`, yielding the part read so far as
+    /// an illegal token the parser skips
+    fn read_illegal_numeric_literal(
+        &mut self,
+        err: LexerError,
+        start: usize,
+    ) -> (Kind, Option<Value>) {
+        let end = self.offset();
+        self.report_error(err, start..end);
+        self.clear_buffer();
+
+        (Kind::Illegal, None)
+    }
+
     pub(crate) fn clear_buffer(&mut self) {
         self.buffer.clear();
     }
 
-    pub(crate) fn flush_buffer(&mut self) -> String {
-        let raw = self.buffer.clone();
-
+    /// Interns the buffer and clears it, keeping its allocation for the next token
+    pub(crate) fn flush_buffer_atom(&mut self) -> Atom {
+        let atom = atom!(self.buffer.as_str());
         self.clear_buffer();
 
-        raw
+        atom
     }
 
     fn report_error(&mut self, err: LexerError, span: Range<usize>) {
+        let span = Span::new(self.file, span);
+
         match err {
             LexerError::NonAsciiCharacter(ch) => {
                 self.context
                     .add_error(error_builders::build_non_ascii_character_error(span, ch));
             }
+            LexerError::ConfusableCharacter { ch, confusable } => {
+                self.context
+                    .add_error(error_builders::build_confusable_character_error(
+                        span,
+                        ch,
+                        confusable.name,
+                        confusable.ascii,
+                    ));
+            }
+            LexerError::ConfusableIdentifier {
+                identifier,
+                ch,
+                confusable,
+            } => {
+                self.context
+                    .add_error(error_builders::build_confusable_identifier_warning(
+                        span,
+                        &identifier,
+                        ch,
+                        confusable.name,
+                        confusable.ascii,
+                    ));
+            }
+            LexerError::InvisibleCharacter {
+                ch,
+                name,
+                in_literal,
+            } => {
+                self.context
+                    .add_error(error_builders::build_invisible_character_error(
+                        span, ch, name, in_literal,
+                    ));
+            }
+            LexerError::UnexpectedCharacter(ch) => {
+                self.context
+                    .add_error(error_builders::build_unexpected_character_error(span, ch));
+            }
             LexerError::UnterminatedString => {
                 self.context
                     .add_error(error_builders::build_unterminated_string_error(span));
@@ -499,15 +740,24 @@ impl<'a> Lexer<'a> {
             LexerError::InvalidUnicode => {
                 self.context
                     .add_error(error_builders::build_invalid_unicode_codepoint_error(
-                        span.clone(),
-                        &self.source[span],
+                        span,
+                        &self.source[span.range()],
                     ));
             }
-            LexerError::UnicodeEscape => unimplemented!(),
+            LexerError::UnicodeEscape => {
+                self.context
+                    .add_error(error_builders::build_surrogate_escape_error(span));
+            }
             LexerError::IllegalCharacter { ch, ctx } => {
+                // Point at the last character read
+                let start = self.source[..span.end]
+                    .char_indices()
+                    .next_back()
+                    .map_or(span.end, |(offset, _)| offset);
+
                 self.context
                     .add_error(error_builders::build_illegal_character_error(
-                        span.end - 1..span.end,
+                        Span { start, ..span },
                         ch,
                         ctx,
                     ));
@@ -516,32 +766,134 @@ impl<'a> Lexer<'a> {
                 self.context
                     .add_error(error_builders::build_unexpected_end_error(span, ctx));
             }
+            LexerError::InvalidDigit { ch, radix } => {
+                self.context
+                    .add_error(error_builders::build_invalid_digit_error(span, ch, radix));
+            }
+            LexerError::EmptyRadixLiteral { prefix, radix } => {
+                self.context
+                    .add_error(error_builders::build_empty_radix_literal_error(
+                        span, &prefix, radix,
+                    ));
+            }
+            LexerError::FractionalRadixLiteral { radix } => {
+                self.context
+                    .add_error(error_builders::build_fractional_radix_literal_error(
+                        span, radix,
+                    ));
+            }
+            LexerError::IntegerOutOfRange => {
+                self.context
+                    .add_error(error_builders::build_integer_out_of_range_error(span));
+            }
+            LexerError::FloatOutOfRange => {
+                self.context
+                    .add_error(error_builders::build_float_out_of_range_error(span));
+            }
+            LexerError::MalformedFloat(literal) => {
+                self.context
+                    .add_error(error_builders::build_malformed_float_error(span, &literal));
+            }
+            LexerError::InvalidSuffix(suffix) => {
+                self.context
+                    .add_error(error_builders::build_invalid_suffix_error(span, &suffix));
+            }
+            LexerError::MismatchedSuffix { suffix, literal } => {
+                self.context
+                    .add_error(error_builders::build_mismatched_suffix_error(
+                        span, suffix, literal,
+                    ));
+            }
+            LexerError::SuffixOutOfRange { suffix, range } => {
+                self.context
+                    .add_error(error_builders::build_suffix_out_of_range_error(
+                        span,
+                        suffix.as_str(),
+                        &range,
+                    ));
+            }
+            LexerError::UnterminatedRawString { hashes } => {
+                self.context
+                    .add_error(error_builders::build_unterminated_raw_string_error(
+                        span, hashes,
+                    ));
+            }
+            LexerError::UnterminatedMultilineString => {
+                self.context
+                    .add_error(error_builders::build_unterminated_multiline_string_error(
+                        span,
+                    ));
+            }
+            LexerError::EmptyInterpolation => {
+                self.context
+                    .add_error(error_builders::build_empty_interpolation_error(span));
+            }
+            LexerError::UnterminatedInterpolation => {
+                self.context
+                    .add_error(error_builders::build_unterminated_interpolation_error(span));
+            }
+            LexerError::UnescapedBrace => {
+                self.context
+                    .add_error(error_builders::build_unescaped_brace_error(span));
+            }
+            LexerError::UnterminatedChar => {
+                self.context
+                    .add_error(error_builders::build_unterminated_char_error(span));
+            }
+            LexerError::EmptyChar => {
+                self.context
+                    .add_error(error_builders::build_empty_char_error(span));
+            }
+            LexerError::MultipleCharacters(value) => {
+                self.context
+                    .add_error(error_builders::build_multiple_characters_error(
+                        span, &value,
+                    ));
+            }
+            LexerError::UnterminatedBlockComment => {
+                self.context
+                    .add_error(error_builders::build_unterminated_block_comment_error(span));
+            }
         }
     }
 
-    fn read_identifier(&mut self, initial_char: char) -> (Kind, Option<Value>) {
-        self.clear_buffer();
-        self.buffer.push(initial_char);
+    pub(crate) fn peek_byte(&self) -> Option<u8> {
+        self.source.as_bytes().get(self.cursor).copied()
+    }
 
-        while let Some('_' | 'a'..='z' | 'A'..='Z' | '0'..='9') = self.peek() {
-            self.push_to_buffer();
+    /// The next character, only decoded from UTF-8 when it is not ASCII
+    pub(crate) fn peek(&self) -> Option<char> {
+        match self.peek_byte()? {
+            byte if byte.is_ascii() => Some(byte as char),
+            _ => self.rest().chars().next(),
         }
+    }
 
-        let value = self.flush_buffer();
+    pub(crate) fn next_char(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.cursor += c.len_utf8();
 
-        match value.as_ref() {
-            "true" => (Kind::BooleanLiteral, Some(Value::Boolean(true))),
-            "false" => (Kind::BooleanLiteral, Some(Value::Boolean(false))),
-            other if is_keyword(other) => (str_to_keyword_kind(other).unwrap(), None),
-            other => (Kind::Identifier, Some(Value::String(atom!(other)))),
-        }
+        Some(c)
     }
 
-    pub(crate) fn peek(&self) -> Option<char> {
-        self.chars.clone().next()
+    pub(crate) fn push_to_buffer(&mut self) {
+        let c = self.next_char().unwrap();
+        self.buffer.push(c);
     }
+}
 
-    pub(crate) fn push_to_buffer(&mut self) {
-        self.buffer.push(self.chars.next().unwrap());
+/// Yields tokens on demand, ending with a single `Kind::Eof` token
+impl<'src> Iterator for Lexer<'_, '_, 'src> {
+    type Item = Token<'src>;
+
+    fn next(&mut self) -> Option<Token<'src>> {
+        if self.finished {
+            return None;
+        }
+
+        let token = self.read_next();
+        self.finished = token.kind == Kind::Eof;
+
+        Some(token)
     }
 }
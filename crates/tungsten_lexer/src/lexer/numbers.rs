@@ -1,33 +1,152 @@
-use crate::{errors::LexerError, numeric_result::NumericResult, Lexer};
+use crate::{
+    errors::LexerError, numeric_result::NumericResult, suffix_primitive_type, Kind, Lexer,
+    PrimitiveType, Value,
+};
 
-impl Lexer<'_> {
-    pub(crate) fn read_hex_4_digits(&mut self) -> Result<char, LexerError> {
-        let mut value = 0;
-        for _ in 0..4 {
-            value = (value << 4) | self.read_hex_digit()?;
+impl Lexer<'_, '_, '_> {
+    /// Converts the buffered digits of a numeric literal starting at `start`, and its optional
+    /// type suffix, into its token. Values that cannot be represented are reported and recovered,
+    /// so lexing can continue.
+    pub(crate) fn finish_numeric_literal(
+        &mut self,
+        result: NumericResult,
+        start: usize,
+    ) -> (Kind, Option<Value>) {
+        // Taken rather than borrowed, as errors are reported while reading the digits, and put
+        // back so its allocation is reused by the next literal
+        let mut digits = std::mem::take(&mut self.buffer);
+        let token = self.numeric_literal_value(&digits, result, start);
+
+        digits.clear();
+        self.buffer = digits;
+
+        token
+    }
+
+    fn numeric_literal_value(
+        &mut self,
+        digits: &str,
+        result: NumericResult,
+        start: usize,
+    ) -> (Kind, Option<Value>) {
+        let suffix = self.read_numeric_suffix(&result);
+        let span = start..self.offset();
+
+        let radix = match result {
+            // A float suffix makes a decimal integer a float, eg: `1f32`
+            NumericResult::Integer if !suffix.is_some_and(|suffix| suffix.is_float()) => 10,
+            NumericResult::Radix(radix) => radix,
+            NumericResult::Integer | NumericResult::Float => {
+                let value = match digits.parse::<f64>() {
+                    Ok(value) if value.is_finite() => {
+                        if suffix == Some(PrimitiveType::F32) && !(value as f32).is_finite() {
+                            self.report_error(
+                                LexerError::SuffixOutOfRange {
+                                    suffix: PrimitiveType::F32,
+                                    range: format!("{:e}..={:e}", f32::MIN, f32::MAX),
+                                },
+                                span,
+                            );
+                        }
+
+                        value
+                    }
+                    Ok(_) => {
+                        self.report_error(LexerError::FloatOutOfRange, span);
+                        f64::MAX
+                    }
+                    // Digits follow the float grammar once lexed, this only guards against a
+                    // mismatch with the one of `f64::from_str`
+                    Err(_) => {
+                        self.report_error(LexerError::MalformedFloat(digits.to_string()), span);
+                        0.0
+                    }
+                };
+
+                return (Kind::FloatLiteral, Some(Value::Float(value, suffix)));
+            }
+        };
+
+        // Digits were validated while lexing, so overflow is the only possible failure
+        let value = u64::from_str_radix(digits, radix).unwrap_or_else(|_| {
+            self.report_error(LexerError::IntegerOutOfRange, span.clone());
+            u64::MAX
+        });
+
+        if let Some((min, max)) = suffix.and_then(PrimitiveType::integer_range) {
+            // Literals are unsigned, and the magnitude of a signed minimum is valid negated, eg:
+            // `-128i8`, which only the parser can tell
+            let is_negated_minimum = min < 0 && value as i128 == -min;
+
+            if value as i128 > max && !is_negated_minimum {
+                self.report_error(
+                    LexerError::SuffixOutOfRange {
+                        suffix: suffix.unwrap(),
+                        range: format!("{min}..={max}"),
+                    },
+                    span,
+                );
+            }
         }
 
-        Self::code_point_to_char(value)
+        (Kind::IntegerLiteral, Some(Value::Integer(value, suffix)))
     }
 
-    pub(crate) fn read_hex_digit(&mut self) -> Result<u32, LexerError> {
-        match self.chars.next() {
-            None => Err(LexerError::InvalidEscape(self.buffer.clone())),
-            Some(c @ '0'..='9') => Ok(c as u32 - '0' as u32),
-            Some(c @ 'a'..='f') => Ok(10 + (c as u32 - 'a' as u32)),
-            Some(c @ 'A'..='F') => Ok(10 + (c as u32 - 'A' as u32)),
-            Some(c) => Err(LexerError::IllegalCharacter {
-                ch: c,
-                ctx: "hex digit",
-            }),
+    /// Reads the type suffix directly following the digits of a numeric literal, eg: `u8`
+    fn read_numeric_suffix(&mut self, result: &NumericResult) -> Option<PrimitiveType> {
+        let start = self.offset();
+
+        while let Some('_' | 'a'..='z' | 'A'..='Z' | '0'..='9') = self.peek() {
+            self.next_char();
         }
+
+        let span = start..self.offset();
+        let text = &self.source[span.clone()];
+
+        if text.is_empty() {
+            return None;
+        }
+
+        let Some(suffix) = suffix_primitive_type(text) else {
+            self.report_error(LexerError::InvalidSuffix(text.to_string()), span);
+            return None;
+        };
+
+        let literal = match result {
+            NumericResult::Float if !suffix.is_float() => "float",
+            NumericResult::Radix(_) if suffix.is_float() => "integer",
+            _ => return Some(suffix),
+        };
+
+        self.report_error(
+            LexerError::MismatchedSuffix {
+                suffix: suffix.as_str(),
+                literal,
+            },
+            span,
+        );
+
+        None
     }
 
     pub(crate) fn read_numeric_literal_starting_with_zero(
         &mut self,
     ) -> Result<NumericResult, LexerError> {
+        let prefix_start = self.offset() - 1;
+
         match self.peek() {
-            Some('.') => {
+            Some(c @ ('x' | 'X' | 'o' | 'O' | 'b' | 'B')) => {
+                self.next_char();
+
+                let radix = match c.to_ascii_lowercase() {
+                    'x' => 16,
+                    'o' => 8,
+                    _ => 2,
+                };
+
+                return self.read_radix_literal(radix, prefix_start);
+            }
+            Some('.') if !self.is_range_ahead() => {
                 self.push_to_buffer();
                 self.read_float_after_decimal_point_after_digits()?;
                 return Ok(NumericResult::Float);
@@ -81,9 +200,9 @@ impl Lexer<'_> {
                 ch: c,
                 ctx: "number",
             });
-        } else if self.peek().is_none() {
+        } else {
             return Err(LexerError::UnexpectedEnd("number"));
-        };
+        }
 
         self.read_decimal_digits_after_first_digit()?;
 
@@ -96,18 +215,14 @@ impl Lexer<'_> {
         self.read_decimal_digits_after_first_digit()?;
 
         if let Some('.') = self.peek() {
+            if self.is_range_ahead() {
+                return Ok(NumericResult::Integer);
+            }
+
             self.push_to_buffer();
             return self.read_float_after_decimal_point_after_digits();
         }
 
-        // match self.peek() {
-        //     Some('.') => {
-        //         self.push_to_buffer();
-        //         return self.read_float_after_decimal_point_after_digits();
-        //     }
-        //     _ => {}
-        // }
-
         let has_exponent = self.read_optional_exponent()?;
         let result = if has_exponent {
             NumericResult::Float
@@ -119,23 +234,32 @@ impl Lexer<'_> {
     }
 
     pub(crate) fn read_decimal_digits_after_first_digit(&mut self) -> Result<(), LexerError> {
+        self.read_digits_after_first_digit(10)
+    }
+
+    /// Reads digits in `radix`, allowing single `_` separators between them and before a type
+    /// suffix, eg: `1_000_u16`
+    pub(crate) fn read_digits_after_first_digit(&mut self, radix: u32) -> Result<(), LexerError> {
         while let Some(next) = self.peek() {
             match next {
                 '_' => {
-                    self.chars.next();
+                    self.next_char();
 
-                    if let Some('0'..='9') = self.peek() {
-                        self.push_to_buffer();
+                    if let Some(c) = self.peek().filter(|c| c.is_digit(radix)) {
+                        self.buffer.push(c);
+                        self.next_char();
+                    } else if let Some('i' | 'u' | 'f') = self.peek() {
+                        break;
                     } else if let Some(c) = self.peek() {
                         return Err(LexerError::IllegalCharacter {
                             ch: c,
                             ctx: "number",
                         });
-                    } else if self.peek().is_none() {
+                    } else {
                         return Err(LexerError::UnexpectedEnd("number"));
-                    };
+                    }
                 }
-                '0'..='9' => {
+                c if c.is_digit(radix) => {
                     self.push_to_buffer();
                 }
                 _ => break,
@@ -145,6 +269,63 @@ impl Lexer<'_> {
         Ok(())
     }
 
+    /// Reads the digits of a `0x`, `0o` or `0b` literal after its prefix. Invalid digits and
+    /// missing digits are reported here, recovering with the digits read so far.
+    pub(crate) fn read_radix_literal(
+        &mut self,
+        radix: u32,
+        prefix_start: usize,
+    ) -> Result<NumericResult, LexerError> {
+        // Drop the leading zero of the prefix
+        self.clear_buffer();
+        self.read_digits_after_first_digit(radix)?;
+
+        // Digits and letters directly following are outside the radix, eg: `0b12` or `0o9`, unless
+        // they start a type suffix
+        if let Some(ch) = self
+            .peek()
+            .filter(|c| c.is_ascii_alphanumeric() && !matches!(c, 'i' | 'u' | 'f'))
+        {
+            let offset = self.offset();
+            self.report_error(LexerError::InvalidDigit { ch, radix }, offset..offset + 1);
+
+            while let Some('_' | 'a'..='z' | 'A'..='Z' | '0'..='9') = self.peek() {
+                self.next_char();
+            }
+        } else if self.buffer.is_empty() {
+            let prefix = self.source[prefix_start..prefix_start + 2].to_string();
+            self.report_error(
+                LexerError::EmptyRadixLiteral { prefix, radix },
+                prefix_start..prefix_start + 2,
+            );
+        }
+
+        // Only decimal literals can be floats, eg: `0x1.5`. The fractional part belongs to the
+        // literal, so it does not lex as a float of its own
+        if self.peek() == Some('.') && self.rest()[1..].starts_with(|c: char| c.is_ascii_digit()) {
+            let start = self.offset();
+            self.next_char();
+
+            while let Some('_' | 'a'..='z' | 'A'..='Z' | '0'..='9') = self.peek() {
+                self.next_char();
+            }
+
+            let end = self.offset();
+            self.report_error(LexerError::FractionalRadixLiteral { radix }, start..end);
+        }
+
+        if self.buffer.is_empty() {
+            self.buffer.push('0');
+        }
+
+        Ok(NumericResult::Radix(radix))
+    }
+
+    /// Whether the upcoming `.` starts a range operator (`..`) rather than a decimal point
+    pub(crate) fn is_range_ahead(&self) -> bool {
+        self.rest().starts_with("..")
+    }
+
     pub(crate) fn read_optional_exponent(&mut self) -> Result<bool, LexerError> {
         if let Some('e') | Some('E') = self.peek() {
             self.push_to_buffer();
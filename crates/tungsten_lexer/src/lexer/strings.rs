@@ -1,5 +1,7 @@
 use crate::{errors::LexerError, Lexer};
 
+use super::{char_class, interpolation::StringEnd};
+
 // U+0009 CHARACTER TABULATION, abbreviated <TAB>.
 const TAB: char = '\u{9}';
 
@@ -21,25 +23,217 @@ const LS: char = '\u{2028}';
 /// U+2029 PARAGRAPH SEPARATOR, abbreviated <PS>.
 const PS: char = '\u{2029}';
 
-impl Lexer<'_> {
-    pub(crate) fn read_string_literal(&mut self) -> Result<(), LexerError> {
+impl Lexer<'_, '_, '_> {
+    /// Reads a string literal, or the fragment of one following an interpolation, up to its
+    /// closing `"` or the `{` of the next interpolation. Literal braces are written `\{` and `\}`.
+    pub(crate) fn read_string_literal(&mut self) -> Result<StringEnd, LexerError> {
         loop {
-            match self.chars.next() {
-                None | Some('\r') | Some('\n') => return Err(LexerError::UnterminatedString),
-                Some(_c @ '"') => break,
-                Some('\\') => {
+            // Copy the plain text up to the next special character at once
+            let len = self.count_bytes_not_in(char_class::STRING_SPECIAL);
+            self.buffer.push_str(&self.rest()[..len]);
+            self.skip_bytes(len);
+
+            // The line break is left in place, so the error does not span the next line
+            let Some(c) = self.peek().filter(|c| !matches!(c, '\r' | '\n')) else {
+                return Err(LexerError::UnterminatedString);
+            };
+            self.next_char();
+
+            match c {
+                '"' => break,
+                '{' => {
+                    let start = self.offset() - 1;
+                    let rest = self.rest();
+                    let trimmed = rest.trim_start_matches([' ', TAB]);
+
+                    if !trimmed.starts_with('}') {
+                        return Ok(StringEnd::Interpolation);
+                    }
+
+                    // Skip the empty interpolation, eg: `{}` or `{ }`
+                    self.skip_bytes(rest.len() - trimmed.len() + 1);
+
+                    let end = self.offset();
+                    self.report_error(LexerError::EmptyInterpolation, start..end);
+                }
+                '}' => {
+                    let offset = self.offset();
+                    self.report_error(LexerError::UnescapedBrace, offset - 1..offset);
+                    self.buffer.push('}');
+                }
+                '\\' => {
                     self.read_escape_sequence()?;
                 }
+                other => {
+                    self.buffer.push(other);
+                }
+            }
+        }
+        Ok(StringEnd::Closed)
+    }
+
+    /// Whether the `r` just read starts a raw string literal: `r`, any number of `#`, then `"`
+    pub(crate) fn is_raw_string_ahead(&self) -> bool {
+        self.rest().trim_start_matches('#').starts_with('"')
+    }
+
+    /// Reads a raw string literal after its `r`. The contents are taken verbatim, with no escape
+    /// processing, up to a `"` followed by as many `#` as the opening delimiter had.
+    pub(crate) fn read_raw_string_literal(&mut self) -> Result<(), LexerError> {
+        let mut hashes = 0;
+        while self.peek() == Some('#') {
+            self.next_char();
+            hashes += 1;
+        }
+
+        // Opening quote
+        self.next_char();
+
+        let closing = "#".repeat(hashes);
+
+        loop {
+            match self.next_char() {
+                None => return Err(LexerError::UnterminatedRawString { hashes }),
+                Some('"') if self.rest().starts_with(&closing) => {
+                    self.skip_bytes(hashes);
+
+                    break;
+                }
                 Some(other) => {
                     self.buffer.push(other);
                 }
             }
         }
+
         Ok(())
     }
 
-    pub(crate) fn read_escape_sequence(&mut self) -> Result<(), LexerError> {
-        match self.chars.next() {
+    /// Reads a multi-line string literal after its opening `"""`, processing escapes. Braces are
+    /// literal, multi-line strings are not interpolated.
+    ///
+    /// A line break directly after the opening delimiter is dropped. When the closing `"""` is on
+    /// its own line, its indentation is stripped from every line and the line break before it is
+    /// dropped, so the literal can be indented along with the surrounding code.
+    pub(crate) fn read_multiline_string_literal(&mut self) -> Result<(), LexerError> {
+        if self.rest().starts_with("\r\n") {
+            self.skip_bytes(2);
+        } else if self.peek() == Some(LF) {
+            self.next_char();
+        }
+
+        let indentation = self.multiline_string_indentation();
+        self.skip_indentation(indentation);
+
+        loop {
+            match self.next_char() {
+                None => return Err(LexerError::UnterminatedMultilineString),
+                Some('"') if self.rest().starts_with("\"\"") => {
+                    self.skip_bytes(2);
+                    break;
+                }
+                Some('\\') => {
+                    self.read_escape_sequence()
+                        .map_err(|_| LexerError::UnterminatedMultilineString)?;
+                }
+                Some(CR) if self.peek() == Some(LF) => {}
+                Some(LF) => {
+                    self.buffer.push(LF);
+                    self.skip_indentation(indentation);
+                }
+                Some(other) => {
+                    self.buffer.push(other);
+                }
+            }
+        }
+
+        if indentation.is_some() && self.buffer.ends_with(LF) {
+            self.buffer.pop();
+        }
+
+        Ok(())
+    }
+
+    /// Indentation of the closing `"""` of the multi-line string ahead, if it is on its own line
+    fn multiline_string_indentation(&self) -> Option<usize> {
+        let rest = self.rest();
+        let mut chars = rest.char_indices();
+
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '"' if rest[index..].starts_with("\"\"\"") => {
+                    let line_start = rest[..index].rfind(LF).map_or(0, |newline| newline + 1);
+                    let prefix = &rest[line_start..index];
+
+                    return prefix
+                        .chars()
+                        .all(|c| c == ' ' || c == TAB)
+                        .then_some(prefix.len());
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    fn skip_indentation(&mut self, indentation: Option<usize>) {
+        for _ in 0..indentation.unwrap_or(0) {
+            match self.peek() {
+                Some(' ') | Some(TAB) => {
+                    self.next_char();
+                }
+                _ => break,
+            }
+        }
+    }
+
+    /// Reads a character literal after its opening `'`, which must hold exactly one character or
+    /// escape sequence
+    pub(crate) fn read_char_literal(&mut self) -> Result<char, LexerError> {
+        loop {
+            // The line break is left in place, so the error does not span the next line
+            let Some(c) = self.peek().filter(|c| !matches!(c, '\r' | '\n')) else {
+                return Err(LexerError::UnterminatedChar);
+            };
+            self.next_char();
+
+            match c {
+                '\'' => break,
+                '\\' => {
+                    // The escape is reported already, stand in for it so the literal is not
+                    // reported empty as well
+                    let valid = self
+                        .read_escape_sequence()
+                        .map_err(|_| LexerError::UnterminatedChar)?;
+                    if !valid {
+                        self.buffer.push(char::REPLACEMENT_CHARACTER);
+                    }
+                }
+                other => {
+                    self.buffer.push(other);
+                }
+            }
+        }
+
+        let mut chars = self.buffer.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            (None, _) => Err(LexerError::EmptyChar),
+            (Some(_), Some(_)) => Err(LexerError::MultipleCharacters(self.buffer.clone())),
+        }
+    }
+
+    /// Reads an escape sequence after its `\`, returning whether it was valid. Invalid escapes are
+    /// reported and skipped, so the literal is still read up to its end. Fails only at the end of
+    /// the file.
+    pub(crate) fn read_escape_sequence(&mut self) -> Result<bool, LexerError> {
+        let start = self.offset() - 1;
+
+        match self.next_char() {
             None => return Err(LexerError::UnterminatedString),
             Some(c) => match c {
                 LF | LS | PS => {
@@ -49,10 +243,10 @@ impl Lexer<'_> {
                     // LineContinuation, check for the sequence \r\n; otherwise
                     // ignore it.
                     if self.peek() == Some(LF) {
-                        self.chars.next();
+                        self.next_char();
                     }
                 }
-                '\'' | '"' | '\\' => {
+                '\'' | '"' | '\\' | '{' | '}' => {
                     self.buffer.push(c);
                 }
                 'b' => {
@@ -73,73 +267,78 @@ impl Lexer<'_> {
                 'v' => {
                     self.buffer.push(VT);
                 }
-                'x' => {
-                    let mut value = self.read_hex_digit()?;
-                    value = (value << 4) | self.read_hex_digit()?;
+                'x' | 'u' => {
+                    let result = match self.read_code_point_escape(c) {
+                        Some(value) => Self::code_point_to_char(value),
+                        None => Err(LexerError::InvalidEscape(
+                            self.source[start..self.offset()].to_string(),
+                        )),
+                    };
 
-                    match char::try_from(value) {
-                        Err(_) => return Err(LexerError::InvalidEscape(self.buffer.clone())),
+                    match result {
                         Ok(c) => self.buffer.push(c),
+                        Err(err) => {
+                            let end = self.offset();
+                            self.report_error(err, start..end);
+
+                            return Ok(false);
+                        }
                     }
                 }
-                'u' => {
-                    let c = self.read_unicode_escape_sequence_after_backslash_and_u()?;
-                    self.buffer.push(c)
-                }
-                // other => self.buffer.push(other),
                 other => {
-                    let offset = self.offset();
-                    self.report_error(
-                        LexerError::InvalidEscape(format!("\\{other}")),
-                        offset - 2..offset,
-                    );
+                    let end = self.offset();
+                    self.report_error(LexerError::InvalidEscape(format!("\\{other}")), start..end);
+
+                    return Ok(false);
                 }
             },
         }
 
-        Ok(())
+        Ok(true)
     }
 
-    pub(crate) fn read_unicode_escape_sequence_after_backslash_and_u(
-        &mut self,
-    ) -> Result<char, LexerError> {
-        let value = match self.peek() {
-            Some('{') => {
-                self.chars.next();
+    /// Reads the code point of a `\x` or `\u` escape after its letter: two hex digits after `x`,
+    /// and four or any number between braces after `u`. Returns `None`, leaving the first
+    /// unexpected character unread, if the escape is malformed.
+    fn read_code_point_escape(&mut self, letter: char) -> Option<u32> {
+        if letter == 'x' {
+            return self.read_hex_digits(2);
+        }
 
-                let value = self.read_code_point()?;
-                match self.chars.next() {
-                    Some('}') => {}
-                    _ => return Err(LexerError::InvalidEscape(self.buffer.clone())),
-                }
+        if self.peek() != Some('{') {
+            return self.read_hex_digits(4);
+        }
+        self.next_char();
 
-                value
-            }
-            _ => self.read_hex_4_digits()?,
-        };
+        // Saturate, so overlong code points are reported as invalid rather than wrapping
+        let mut value = self.read_hex_digit()?;
+        while let Some(digit) = self.read_hex_digit() {
+            value = value.saturating_mul(16).saturating_add(digit);
+        }
 
-        Ok(value)
-    }
+        if self.peek() != Some('}') {
+            return None;
+        }
+        self.next_char();
 
-    pub(crate) fn read_code_point(&mut self) -> Result<char, LexerError> {
-        let mut value = self.read_hex_digit()?;
+        Some(value)
+    }
 
-        loop {
-            let next = match self.peek() {
-                None => return Err(LexerError::InvalidEscape(self.buffer.clone())),
-                Some(c @ '0'..='9') => c as u32 - '0' as u32,
-                Some(c @ 'a'..='f') => 10 + (c as u32 - 'a' as u32),
-                Some(c @ 'A'..='F') => 10 + (c as u32 - 'A' as u32),
-                Some(_) => break,
-            };
-            self.chars.next();
-            value = (value << 4) | next;
-            if value > 0x10FFFF {
-                return Err(LexerError::InvalidEscape(self.buffer.clone()));
-            }
+    fn read_hex_digits(&mut self, count: usize) -> Option<u32> {
+        let mut value = 0;
+        for _ in 0..count {
+            value = (value << 4) | self.read_hex_digit()?;
         }
 
-        Self::code_point_to_char(value)
+        Some(value)
+    }
+
+    /// Reads the next character if it is a hex digit
+    fn read_hex_digit(&mut self) -> Option<u32> {
+        let digit = self.peek()?.to_digit(16)?;
+        self.next_char();
+
+        Some(digit)
     }
 
     pub(crate) fn code_point_to_char(value: u32) -> Result<char, LexerError> {
@@ -148,11 +347,5 @@ impl Lexer<'_> {
         } else {
             char::try_from(value).map_err(|_| LexerError::InvalidUnicode)
         }
-
-        // if 0xd800 <= value && value <= 0xdfff {
-        //     bail!("Unicode escape sequences (surrogates)")
-        // } else {
-        //     Ok(char::try_from(value)?)
-        // }
     }
 }
@@ -3,6 +3,7 @@ pub use kind::*;
 pub use lexer::*;
 pub use position::*;
 pub use token::*;
+pub use token_stream::*;
 
 mod errors;
 mod keywords;
@@ -11,3 +12,4 @@ mod lexer;
 mod numeric_result;
 mod position;
 mod token;
+mod token_stream;
@@ -1,4 +1,6 @@
 pub(crate) enum NumericResult {
     Float,
     Integer,
+    /// Integer in the given radix, eg: `0xFF`
+    Radix(u32),
 }
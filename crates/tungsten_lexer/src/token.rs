@@ -1,33 +1,27 @@
-use std::ops::Range;
-
+use tungsten_context::Span;
 use tungsten_utils::Atom;
+pub use tungsten_utils::PrimitiveType;
 
 use crate::{kind::Kind, position::Position};
 
-#[derive(Debug, PartialEq)]
-pub struct Token {
-    pub span: Range<usize>,
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token<'src> {
+    pub span: Span,
     pub position: Position,
     pub kind: Kind,
-    pub lexeme: Atom,
+    /// Source text of the token
+    pub lexeme: &'src str,
     pub value: Option<Value>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Value {
     String(Atom),
-    Integer(u64),
-    Float(f64),
+    /// Integer literal and its type suffix, eg: the `u8` in `10u8`
+    Integer(u64, Option<PrimitiveType>),
+    /// Float literal and its type suffix, eg: the `f32` in `1.5f32`
+    Float(f64, Option<PrimitiveType>),
     Boolean(bool),
     Character(char),
     Primitive(PrimitiveType),
 }
-
-#[derive(Debug, PartialEq)]
-pub enum PrimitiveType {
-    String,
-    Boolean,
-    UnsignedInteger,
-    SignedInteger,
-    Float,
-}
@@ -0,0 +1,162 @@
+use std::collections::VecDeque;
+
+use tungsten_context::Span;
+
+use crate::{Kind, Position, Token};
+
+/// Buffered token stream over a [`Lexer`](crate::Lexer) or any other token source, with arbitrary
+/// lookahead and checkpoints for speculative parsing.
+///
+/// Only the tokens after the oldest live checkpoint are kept, so a stream read without
+/// checkpoints never holds more than its lookahead. Past the end, the stream keeps yielding the
+/// same `Kind::Eof` token, which is synthesized if the source does not end with one.
+#[derive(Debug)]
+pub struct TokenStream<'src, I: Iterator<Item = Token<'src>>> {
+    tokens: I,
+    /// Tokens pulled from `tokens` and not yet released, `buffer[0]` being token number `base`
+    buffer: VecDeque<Token<'src>>,
+    base: usize,
+    /// Number of the next token
+    position: usize,
+    /// Positions saved by live checkpoints, innermost last
+    checkpoints: Vec<usize>,
+    eof: Option<Token<'src>>,
+    /// End of the last token pulled from `tokens`, where a synthesized `Kind::Eof` goes
+    last_end: Option<(Span, Position)>,
+}
+
+/// Position in a [`TokenStream`] to rewind to. Checkpoints must be rewound or committed
+/// innermost first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[must_use]
+pub struct Checkpoint {
+    position: usize,
+    depth: usize,
+}
+
+impl<'src, I: Iterator<Item = Token<'src>>> TokenStream<'src, I> {
+    pub fn new(tokens: impl IntoIterator<IntoIter = I>) -> Self {
+        Self {
+            tokens: tokens.into_iter(),
+            buffer: VecDeque::new(),
+            base: 0,
+            position: 0,
+            checkpoints: Vec::new(),
+            eof: None,
+            last_end: None,
+        }
+    }
+
+    pub fn peek(&mut self) -> &Token<'src> {
+        self.peek_nth(0)
+    }
+
+    /// Returns the token `n` tokens ahead of the next one, `peek_nth(0)` being the next token
+    pub fn peek_nth(&mut self, n: usize) -> &Token<'src> {
+        let index = self.position + n - self.base;
+        self.fill(index + 1);
+
+        match self.buffer.get(index) {
+            Some(token) => token,
+            None => self
+                .eof
+                .as_ref()
+                .expect("exhausted token stream without `Eof`"),
+        }
+    }
+
+    /// Number of tokens consumed so far, less any rewound
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn is_at_eof(&mut self) -> bool {
+        self.peek().kind == Kind::Eof
+    }
+
+    /// Consumes the next token, returning `Kind::Eof` forever once the end is reached
+    pub fn advance(&mut self) -> Token<'src> {
+        let token = self.peek().clone();
+
+        if token.kind != Kind::Eof {
+            self.position += 1;
+            self.release();
+        }
+
+        token
+    }
+
+    pub fn checkpoint(&mut self) -> Checkpoint {
+        self.checkpoints.push(self.position);
+
+        Checkpoint {
+            position: self.position,
+            depth: self.checkpoints.len() - 1,
+        }
+    }
+
+    /// Goes back to `checkpoint`, discarding it and any checkpoint taken after it
+    pub fn rewind(&mut self, checkpoint: Checkpoint) {
+        self.discard(checkpoint);
+        self.position = checkpoint.position;
+        self.release();
+    }
+
+    /// Keeps the tokens consumed since `checkpoint`, discarding it and any checkpoint taken
+    /// after it
+    pub fn commit(&mut self, checkpoint: Checkpoint) {
+        self.discard(checkpoint);
+        self.release();
+    }
+
+    fn discard(&mut self, checkpoint: Checkpoint) {
+        debug_assert_eq!(
+            self.checkpoints.get(checkpoint.depth),
+            Some(&checkpoint.position),
+            "checkpoint was already discarded"
+        );
+
+        self.checkpoints.truncate(checkpoint.depth);
+    }
+
+    /// Pulls tokens until `len` are buffered or the source is exhausted
+    fn fill(&mut self, len: usize) {
+        while self.buffer.len() < len && self.eof.is_none() {
+            match self.tokens.next() {
+                Some(token) if token.kind == Kind::Eof => self.eof = Some(token),
+                Some(token) => {
+                    self.last_end = Some((token.span, token.position));
+                    self.buffer.push_back(token);
+                }
+                None => self.eof = Some(self.synthesize_eof()),
+            }
+        }
+    }
+
+    /// Drops the consumed tokens no checkpoint can rewind to
+    fn release(&mut self) {
+        let keep_from = self.checkpoints.first().copied().unwrap_or(self.position);
+
+        while self.base < keep_from && !self.buffer.is_empty() {
+            self.buffer.pop_front();
+            self.base += 1;
+        }
+    }
+
+    fn synthesize_eof(&self) -> Token<'src> {
+        let (span, position) = self
+            .last_end
+            .unwrap_or((Span::default(), Position::new(1, 1)));
+
+        Token {
+            span: Span {
+                start: span.end,
+                ..span
+            },
+            position,
+            kind: Kind::Eof,
+            lexeme: "",
+            value: None,
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Lexing of line, block and doc comments.
+
+mod common;
+
+use tungsten_lexer::{Kind, Token, Value};
+
+/// Lexes `source` with trivia preserved, as [`common::lex`] does
+fn lex_trivia(source: &str) -> (Vec<Token<'_>>, Vec<String>) {
+    common::lex_with(source, |lexer| {
+        lexer.set_preserve_trivia(true);
+    })
+}
+
+#[test]
+fn comments_are_skipped() {
+    let source = "a # line ]# #[\nb #[ block ]# c #[ outer #[ inner ]# still ]# d#[]#e";
+
+    assert_eq!(
+        common::kinds(source),
+        vec![
+            (Kind::Identifier, "a"),
+            (Kind::Identifier, "b"),
+            (Kind::Identifier, "c"),
+            (Kind::Identifier, "d"),
+            (Kind::Identifier, "e"),
+        ]
+    );
+}
+
+#[test]
+fn comments_are_trivia_when_preserved() {
+    let source = "a #[ x #[ y ]# ]#\n# line\r\nb";
+    let (tokens, diagnostics) = lex_trivia(source);
+
+    assert_eq!(diagnostics, Vec::<String>::new());
+    assert_eq!(
+        common::lexemes(source, &tokens),
+        vec![
+            (Kind::Identifier, "a"),
+            (Kind::Whitespace, " "),
+            (Kind::Comment, "#[ x #[ y ]# ]#"),
+            (Kind::Newline, "\n"),
+            (Kind::Comment, "# line\r"),
+            (Kind::Newline, "\n"),
+            (Kind::Identifier, "b"),
+        ]
+    );
+}
+
+#[test]
+fn unterminated_block_comments() {
+    let cases = [
+        ("a #[ never closed", vec![(Kind::Identifier, "a")]),
+        ("a #[ outer #[ inner ]#\nb", vec![(Kind::Identifier, "a")]),
+        ("#[#[]#", vec![]),
+    ];
+
+    for (source, expected) in cases {
+        let (tokens, diagnostics) = common::lex(source);
+
+        assert_eq!(
+            common::lexemes(source, &tokens),
+            expected,
+            "lexing `{source}`"
+        );
+        // Pointing at the outermost `#[`, not the end of the file
+        assert_eq!(diagnostics, vec!["E011 [\"#[\"]"], "lexing `{source}`");
+    }
+
+    let source = "x #[ open";
+    let (tokens, diagnostics) = lex_trivia(source);
+    assert_eq!(
+        common::lexemes(source, &tokens).last(),
+        Some(&(Kind::Comment, "#[ open"))
+    );
+    assert_eq!(diagnostics, vec!["E011 [\"#[\"]"]);
+}
+
+#[test]
+fn doc_comments() {
+    let (tokens, diagnostics) = common::lex("## First line\r\n##  indented\n##\n###x\nvar");
+    assert_eq!(diagnostics, Vec::<String>::new());
+
+    let docs: Vec<_> = tokens
+        .iter()
+        .map(|token| (token.kind, token.value.clone()))
+        .collect();
+    let doc = |text: &str| (Kind::DocComment, Some(Value::String(text.into())));
+
+    // Only the `##` and a single space are stripped, as well as the `\r` of a `\r\n`
+    assert_eq!(
+        docs,
+        vec![
+            doc("First line"),
+            doc(" indented"),
+            doc(""),
+            doc("#x"),
+            (Kind::VarKw, None),
+        ]
+    );
+}
+
+#[test]
+fn doc_comments_are_never_trivia() {
+    let source = "a ## doc";
+    let (tokens, diagnostics) = lex_trivia(source);
+
+    assert_eq!(diagnostics, Vec::<String>::new());
+    assert_eq!(
+        common::lexemes(source, &tokens),
+        vec![
+            (Kind::Identifier, "a"),
+            (Kind::Whitespace, " "),
+            (Kind::DocComment, "## doc"),
+        ]
+    );
+}
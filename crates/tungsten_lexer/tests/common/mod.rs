@@ -0,0 +1,85 @@
+//! Helpers shared by the lexer tests, each using only some of them.
+#![allow(dead_code)]
+
+use std::path::Path;
+
+use tungsten_context::CompilerContext;
+use tungsten_lexer::{Kind, Lexer, Token};
+
+/// Context compiling `source` as the main file
+pub fn context(source: &str) -> CompilerContext<'_> {
+    CompilerContext::new(Path::new("test.tung"), source, Path::new("target"))
+}
+
+/// Code and labelled source of each diagnostic reported to `ctx` while compiling `source`
+pub fn diagnostics(ctx: &CompilerContext, source: &str) -> Vec<String> {
+    ctx.errors()
+        .iter()
+        .map(|diagnostic| {
+            let labels: Vec<&str> = diagnostic
+                .labels
+                .iter()
+                .map(|label| &source[label.range.clone()])
+                .collect();
+
+            format!(
+                "{} {labels:?}",
+                diagnostic.code.as_deref().unwrap_or_default()
+            )
+        })
+        .collect()
+}
+
+/// Code, labelled source and label message of each diagnostic reported to `ctx` while compiling
+/// `source`
+pub fn labelled_diagnostics(ctx: &CompilerContext, source: &str) -> Vec<String> {
+    ctx.errors()
+        .iter()
+        .map(|diagnostic| {
+            let labels: Vec<_> = diagnostic
+                .labels
+                .iter()
+                .map(|label| (&source[label.range.clone()], label.message.as_str()))
+                .collect();
+
+            format!(
+                "{} {labels:?}",
+                diagnostic.code.as_deref().unwrap_or_default()
+            )
+        })
+        .collect()
+}
+
+/// Lexes `source`, returning its tokens without the final `Kind::Eof` and its diagnostics
+pub fn lex(source: &str) -> (Vec<Token<'_>>, Vec<String>) {
+    lex_with(source, |_| {})
+}
+
+/// Lexes `source` as [`lex`] does, after `configure` has set the lexer's options
+pub fn lex_with(source: &str, configure: impl FnOnce(&mut Lexer)) -> (Vec<Token<'_>>, Vec<String>) {
+    let mut ctx = context(source);
+    let file = ctx.main_file();
+    let mut lexer = Lexer::new(&mut ctx, file, source);
+    configure(&mut lexer);
+
+    let mut tokens = lexer.tokenize();
+    assert_eq!(tokens.pop().map(|token| token.kind), Some(Kind::Eof));
+
+    (tokens, diagnostics(&ctx, source))
+}
+
+/// Kind and lexeme of each of `tokens`, read from `source`
+pub fn lexemes<'src>(source: &'src str, tokens: &[Token]) -> Vec<(Kind, &'src str)> {
+    tokens
+        .iter()
+        .map(|token| (token.kind, &source[token.span.range()]))
+        .collect()
+}
+
+/// Kind and lexeme of each token of `source`, which must lex without diagnostics
+pub fn kinds(source: &str) -> Vec<(Kind, &str)> {
+    let (tokens, diagnostics) = lex(source);
+    assert_eq!(diagnostics, Vec::<String>::new(), "lexing `{source}`");
+
+    lexemes(source, &tokens)
+}
@@ -0,0 +1,97 @@
+//! Diagnostics for characters which look like others or render as nothing.
+
+mod common;
+
+use tungsten_lexer::{Kind, Lexer, Token};
+
+/// Lexes `source`, returning its tokens without the final `Kind::Eof` and the code, labelled
+/// source and label message of each diagnostic
+fn lex(source: &str) -> (Vec<Token<'_>>, Vec<String>) {
+    let mut ctx = common::context(source);
+    let file = ctx.main_file();
+    let mut tokens = Lexer::new(&mut ctx, file, source).tokenize();
+    assert_eq!(tokens.pop().map(|token| token.kind), Some(Kind::Eof));
+
+    (tokens, common::labelled_diagnostics(&ctx, source))
+}
+
+#[test]
+fn confusable_characters() {
+    let cases = [
+        ("x = \u{201C}hi\u{201D};", "\u{201C}", '"'),
+        ("c = \u{2018}a\u{2019};", "\u{2018}", '\''),
+        ("a \u{2013} b", "\u{2013}", '-'),
+        ("a \u{2212} b", "\u{2212}", '-'),
+        ("x\u{FF1B}", "\u{FF1B}", ';'),
+        ("f(a\u{FF0C} b)", "\u{FF0C}", ','),
+        ("a\u{A0}b", "\u{A0}", ' '),
+    ];
+
+    for (source, ch, ascii) in cases {
+        let (tokens, diagnostics) = lex(source);
+        let suggestion = format!("did you mean `{ascii}`?");
+        let expected = format!("E029 {:?}", [(ch, suggestion.as_str())]);
+
+        assert_eq!(diagnostics.first(), Some(&expected), "lexing `{source}`");
+        assert!(
+            common::lexemes(source, &tokens).contains(&(Kind::Illegal, ch)),
+            "lexing `{source}`: {tokens:?}"
+        );
+    }
+
+    // Other characters which cannot start a token get no suggestion
+    assert_eq!(
+        lex("\u{20AC}").1,
+        vec!["E028 [(\"\u{20AC}\", \"unexpected character found here\")]"]
+    );
+}
+
+#[test]
+fn confusable_identifiers() {
+    // A Cyrillic `а` and a Greek `Α` among ASCII letters
+    let cases = [
+        ("p\u{430}ss", "\u{430}", 'a'),
+        ("\u{391}bc", "\u{391}", 'A'),
+        ("t\u{3BF}tal_\u{43E}", "\u{3BF}", 'o'),
+    ];
+
+    for (source, ch, ascii) in cases {
+        let (tokens, diagnostics) = lex(source);
+
+        assert_eq!(
+            diagnostics,
+            vec![format!("W002 [({ch:?}, \"did you mean `{ascii}`?\")]")],
+            "lexing `{source}`"
+        );
+        // The identifier is kept whole, as it may well be intended
+        assert_eq!(tokens.len(), 1, "lexing `{source}`");
+        assert_eq!(tokens[0].kind, Kind::Identifier, "lexing `{source}`");
+    }
+
+    // Identifiers written entirely in another script are not mixed
+    for source in ["\u{430}", "\u{391}\u{392}", "\u{3B1}\u{3B2}\u{3B3}"] {
+        assert_eq!(lex(source).1, Vec::<String>::new(), "lexing `{source}`");
+    }
+}
+
+#[test]
+fn invisible_characters() {
+    let cases = [
+        ("a\u{200B}b", "\u{200B}", "did you mean to remove it?"),
+        ("a \u{FEFF}", "\u{FEFF}", "did you mean to remove it?"),
+        ("# x\u{2066}y\nz", "\u{2066}", "did you mean to remove it?"),
+        ("\"a\u{202E}b\"", "\u{202E}", "did you mean `\\u{202E}`?"),
+        ("'\u{200D}'", "\u{200D}", "did you mean `\\u{200D}`?"),
+        ("\"{x\u{200B}}\"", "\u{200B}", "did you mean to remove it?"),
+        // Raw strings have no escapes to offer instead
+        ("r\"\u{2067}\"", "\u{2067}", "did you mean to remove it?"),
+    ];
+
+    for (source, ch, suggestion) in cases {
+        assert_eq!(
+            lex(source).1,
+            vec![format!("E030 [({ch:?}, {suggestion:?})]")],
+            "lexing `{source}`"
+        );
+    }
+}
@@ -0,0 +1,126 @@
+//! Lexing of Unicode identifiers, and of non-ASCII characters in strict ASCII mode.
+
+mod common;
+
+use tungsten_lexer::{Kind, Lexer, Token, Value};
+
+/// Lexes `source` as [`common::lex`] does, only accepting ASCII identifiers if `strict_ascii` is
+/// set
+fn lex(source: &str, strict_ascii: bool) -> (Vec<Token<'_>>, Vec<String>) {
+    let mut ctx = common::context(source);
+    ctx.set_strict_ascii(strict_ascii);
+
+    let file = ctx.main_file();
+    let mut tokens = Lexer::new(&mut ctx, file, source).tokenize();
+    assert_eq!(tokens.pop().map(|token| token.kind), Some(Kind::Eof));
+
+    (tokens, common::diagnostics(&ctx, source))
+}
+
+/// Name of the single identifier of `source`, which must lex without diagnostics
+fn identifier(source: &str) -> Value {
+    let (tokens, diagnostics) = lex(source, false);
+
+    assert_eq!(diagnostics, Vec::<String>::new(), "lexing `{source}`");
+    assert_eq!(tokens.len(), 1, "lexing `{source}`: {tokens:?}");
+    assert_eq!(tokens[0].kind, Kind::Identifier, "lexing `{source}`");
+    assert_eq!(common::lexemes(source, &tokens)[0].1, source);
+
+    tokens[0].value.clone().unwrap()
+}
+
+#[test]
+fn xid_identifiers() {
+    for source in [
+        "_x1",
+        "café",
+        "αβγ",
+        "变量",
+        "ünïcödé_2",
+        "ǅ",
+        "x·y",
+        "𝑥",
+        "x\u{301}y",
+    ] {
+        identifier(source);
+    }
+
+    // Characters outside of `XID_Start` and `XID_Continue` end the identifier
+    let source = "a€b \u{301}c";
+    let (tokens, diagnostics) = lex(source, false);
+
+    assert_eq!(
+        common::lexemes(source, &tokens),
+        vec![
+            (Kind::Identifier, "a"),
+            (Kind::Illegal, "€"),
+            (Kind::Identifier, "b"),
+            (Kind::Illegal, "\u{301}"),
+            (Kind::Identifier, "c"),
+        ]
+    );
+    assert_eq!(diagnostics, vec!["E028 [\"€\"]", r#"E028 ["\u{301}"]"#]);
+}
+
+#[test]
+fn identifiers_are_nfc_normalized() {
+    let composed = "caf\u{e9}";
+    let decomposed = "cafe\u{301}";
+
+    assert_ne!(composed, decomposed);
+    assert_eq!(identifier(composed), identifier(decomposed));
+    assert_eq!(identifier(decomposed), Value::String(composed.into()));
+
+    // Both spellings bind the same name
+    let (tokens, _) = lex("var caf\u{e9} = 1; cafe\u{301} += 1;", false);
+    let names: Vec<_> = tokens
+        .iter()
+        .filter(|token| token.kind == Kind::Identifier)
+        .map(|token| token.value.clone())
+        .collect();
+    assert_eq!(names[0], names[1]);
+}
+
+#[test]
+fn strict_ascii_mode_rejects_non_ascii() {
+    let cases = [
+        (
+            "café",
+            vec![(Kind::Identifier, "caf"), (Kind::Illegal, "é")],
+            vec!["E001 [\"é\"]"],
+        ),
+        (
+            "αβ x",
+            vec![
+                (Kind::Illegal, "α"),
+                (Kind::Illegal, "β"),
+                (Kind::Identifier, "x"),
+            ],
+            vec!["E001 [\"α\"]", "E001 [\"β\"]"],
+        ),
+        (
+            "a€b",
+            vec![
+                (Kind::Identifier, "a"),
+                (Kind::Illegal, "€"),
+                (Kind::Identifier, "b"),
+            ],
+            vec!["E001 [\"€\"]"],
+        ),
+    ];
+
+    for (source, expected, expected_diagnostics) in cases {
+        let (tokens, diagnostics) = lex(source, true);
+
+        assert_eq!(
+            common::lexemes(source, &tokens),
+            expected,
+            "lexing `{source}`"
+        );
+        assert_eq!(diagnostics, expected_diagnostics, "lexing `{source}`");
+    }
+
+    // Non-ASCII text is still allowed in strings and comments
+    let (_, diagnostics) = lex("\"café\" # naïve", true);
+    assert_eq!(diagnostics, Vec::<String>::new());
+}
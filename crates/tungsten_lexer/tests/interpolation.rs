@@ -0,0 +1,170 @@
+//! Lexing of interpolated string literals into fragments and the tokens of their expressions.
+
+mod common;
+
+use common::{kinds, lex, lexemes};
+use tungsten_lexer::{Kind, Value};
+
+#[test]
+fn fragments() {
+    assert_eq!(
+        kinds(r#""a {x} b {y + 1}c" z"#),
+        vec![
+            (Kind::StringHead, r#""a {"#),
+            (Kind::Identifier, "x"),
+            (Kind::StringMiddle, "} b {"),
+            (Kind::Identifier, "y"),
+            (Kind::Plus, "+"),
+            (Kind::IntegerLiteral, "1"),
+            (Kind::StringTail, r#"}c""#),
+            (Kind::Identifier, "z"),
+        ]
+    );
+
+    // Fragment values hold only the text between the delimiters, escapes processed
+    let (tokens, _) = lex(r#""\t{x}{y}\n""#);
+    let values: Vec<_> = tokens
+        .iter()
+        .filter(|token| token.kind != Kind::Identifier)
+        .map(|token| (token.kind, token.value.clone()))
+        .collect();
+    let string = |text: &str| Some(Value::String(text.into()));
+
+    assert_eq!(
+        values,
+        vec![
+            (Kind::StringHead, string("\t")),
+            (Kind::StringMiddle, string("")),
+            (Kind::StringTail, string("\n")),
+        ]
+    );
+}
+
+#[test]
+fn nested_interpolations() {
+    assert_eq!(
+        kinds(r#""a{ "b{c}" }d""#),
+        vec![
+            (Kind::StringHead, r#""a{"#),
+            (Kind::StringHead, r#""b{"#),
+            (Kind::Identifier, "c"),
+            (Kind::StringTail, r#"}""#),
+            (Kind::StringTail, r#"}d""#),
+        ]
+    );
+
+    // Braces opened inside the interpolation must be closed before it ends
+    assert_eq!(
+        kinds(r#""{ f({a}) } { {|b|} }""#),
+        vec![
+            (Kind::StringHead, r#""{"#),
+            (Kind::Identifier, "f"),
+            (Kind::LParen, "("),
+            (Kind::LBrace, "{"),
+            (Kind::Identifier, "a"),
+            (Kind::RBrace, "}"),
+            (Kind::RParen, ")"),
+            (Kind::StringMiddle, "} {"),
+            (Kind::LBraPipe, "{|"),
+            (Kind::Identifier, "b"),
+            (Kind::RBraPipe, "|}"),
+            (Kind::StringTail, r#"}""#),
+        ]
+    );
+
+    // Whereas a `|}` at the end of the interpolation is a `|` before its `}`
+    assert_eq!(
+        kinds(r#""{|b|}""#),
+        vec![
+            (Kind::StringHead, r#""{"#),
+            (Kind::Pipe, "|"),
+            (Kind::Identifier, "b"),
+            (Kind::Pipe, "|"),
+            (Kind::StringTail, r#"}""#),
+        ]
+    );
+}
+
+#[test]
+fn escaped_braces_are_literal() {
+    let (tokens, diagnostics) = lex(r#""\{x\} {y}""#);
+
+    assert_eq!(diagnostics, Vec::<String>::new());
+    assert_eq!(tokens[0].kind, Kind::StringHead);
+    assert_eq!(tokens[0].value, Some(Value::String("{x} ".into())));
+}
+
+#[test]
+fn malformed_interpolations() {
+    let cases = [
+        (r#""a{}b""#, "ab", r#"E025 ["{}"]"#),
+        (r#""a{  }b""#, "ab", r#"E025 ["{  }"]"#),
+        (r#""a}b""#, "a}b", r#"E027 ["}"]"#),
+    ];
+
+    for (source, value, diagnostic) in cases {
+        let (tokens, diagnostics) = lex(source);
+
+        assert_eq!(diagnostics, vec![diagnostic], "lexing `{source}`");
+        assert_eq!(tokens.len(), 1, "lexing `{source}`: {tokens:?}");
+        assert_eq!(tokens[0].kind, Kind::StringLiteral, "lexing `{source}`");
+        assert_eq!(tokens[0].value, Some(Value::String(value.into())));
+    }
+}
+
+#[test]
+fn unterminated_interpolations() {
+    // The interpolation ends with the line, with an empty tail pointing at the opening `"`
+    let source = "\"a{x\ny";
+    let (tokens, diagnostics) = lex(source);
+    assert_eq!(diagnostics, vec![r#"E026 ["\""]"#]);
+    assert_eq!(
+        lexemes(source, &tokens),
+        vec![
+            (Kind::StringHead, "\"a{"),
+            (Kind::Identifier, "x"),
+            (Kind::StringTail, ""),
+            (Kind::Identifier, "y"),
+        ]
+    );
+
+    let (tokens, diagnostics) = lex(r#"x "a{ "b" "#);
+    assert_eq!(diagnostics, vec![r#"E026 ["\""]"#]);
+    assert_eq!(
+        tokens.last().map(|token| token.kind),
+        Some(Kind::StringTail)
+    );
+
+    // A fragment left open after the interpolation is an unterminated string
+    let source = "\"a{x}b\nc";
+    let (tokens, diagnostics) = lex(source);
+    assert_eq!(diagnostics, vec![r#"E002 ["\"a{x}b"]"#]);
+    assert_eq!(tokens[2].kind, Kind::StringTail);
+    assert_eq!(lexemes(source, &tokens)[3], (Kind::Identifier, "c"));
+}
+
+#[test]
+fn comments_do_not_end_interpolations() {
+    // `#` would otherwise comment out the rest of the string literal
+    let source = r#""a {x # c}" y"#;
+    let (tokens, diagnostics) = lex(source);
+    assert_eq!(diagnostics, vec![r##"E028 ["#"]"##]);
+    assert_eq!(
+        lexemes(source, &tokens),
+        vec![
+            (Kind::StringHead, "\"a {"),
+            (Kind::Identifier, "x"),
+            (Kind::Illegal, "#"),
+            (Kind::Identifier, "c"),
+            (Kind::StringTail, "}\""),
+            (Kind::Identifier, "y"),
+        ]
+    );
+
+    let source = r#""{x ## c}""#;
+    assert_eq!(lex(source).1, vec![r##"E028 ["#"]"##, r##"E028 ["#"]"##]);
+
+    // Comments are still allowed after the string literal
+    let source = r#""{x}" # c"#;
+    assert_eq!(lex(source).1, Vec::<String>::new());
+}
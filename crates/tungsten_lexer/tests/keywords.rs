@@ -0,0 +1,109 @@
+//! Lexing of keywords and primitive type keywords.
+
+mod common;
+
+use tungsten_lexer::{str_to_keyword_kind, suffix_primitive_type, Kind, PrimitiveType, Value};
+
+/// Kind and value of the single token of `source`
+fn lex(source: &str) -> (Kind, Option<Value>) {
+    let (tokens, diagnostics) = common::lex(source);
+
+    assert_eq!(diagnostics, Vec::<String>::new(), "lexing `{source}`");
+    assert_eq!(tokens.len(), 1, "lexing `{source}`: {tokens:?}");
+
+    (tokens[0].kind, tokens[0].value.clone())
+}
+
+const KEYWORDS: &[(&str, Kind)] = &[
+    ("defer", Kind::DeferKw),
+    ("func", Kind::FuncKw),
+    ("do", Kind::DoKw),
+    ("break", Kind::BreakKw),
+    ("continue", Kind::ContinueKw),
+    ("if", Kind::IfKw),
+    ("else", Kind::ElseKw),
+    ("for", Kind::ForKw),
+    ("in", Kind::InKw),
+    ("loop", Kind::LoopKw),
+    ("while", Kind::WhileKw),
+    ("repeat", Kind::RepeatKw),
+    ("until", Kind::UntilKw),
+    ("match", Kind::MatchKw),
+    ("sizeof", Kind::SizeofKw),
+    ("pub", Kind::PubKw),
+    ("module", Kind::ModuleKw),
+    ("import", Kind::ImportKw),
+    ("const", Kind::ConstKw),
+    ("var", Kind::VarKw),
+];
+
+const PRIMITIVE_TYPES: &[(&str, Kind, PrimitiveType)] = &[
+    ("void", Kind::VoidType, PrimitiveType::Void),
+    ("nil", Kind::NilType, PrimitiveType::Nil),
+    ("uint", Kind::UIntType, PrimitiveType::UnsignedInteger),
+    ("int", Kind::IntType, PrimitiveType::SignedInteger),
+    ("float", Kind::FloatType, PrimitiveType::Float),
+    ("bool", Kind::BoolType, PrimitiveType::Boolean),
+    ("str", Kind::StrType, PrimitiveType::String),
+    ("char", Kind::CharType, PrimitiveType::Character),
+    ("i8", Kind::I8Type, PrimitiveType::I8),
+    ("i16", Kind::I16Type, PrimitiveType::I16),
+    ("i32", Kind::I32Type, PrimitiveType::I32),
+    ("i64", Kind::I64Type, PrimitiveType::I64),
+    ("u8", Kind::U8Type, PrimitiveType::U8),
+    ("u16", Kind::U16Type, PrimitiveType::U16),
+    ("u32", Kind::U32Type, PrimitiveType::U32),
+    ("u64", Kind::U64Type, PrimitiveType::U64),
+    ("f32", Kind::F32Type, PrimitiveType::F32),
+    ("f64", Kind::F64Type, PrimitiveType::F64),
+];
+
+#[test]
+fn keywords() {
+    for &(keyword, kind) in KEYWORDS {
+        assert_eq!(lex(keyword), (kind, None), "lexing `{keyword}`");
+        assert_eq!(kind.primitive_type(), None);
+    }
+
+    assert_eq!(lex("|>"), (Kind::ReturnKw, None));
+    assert_eq!(str_to_keyword_kind("|>"), None, "`|>` is an operator");
+}
+
+#[test]
+fn primitive_type_keywords() {
+    for &(keyword, kind, primitive) in PRIMITIVE_TYPES {
+        assert_eq!(
+            lex(keyword),
+            (kind, Some(Value::Primitive(primitive))),
+            "lexing `{keyword}`"
+        );
+        assert_eq!(kind.primitive_type(), Some(primitive));
+        assert_eq!(primitive.as_str(), keyword);
+    }
+}
+
+#[test]
+fn near_keywords_are_identifiers() {
+    for source in ["funcs", "Int", "i128", "u8x", "_if", "var_", "f"] {
+        assert_eq!(lex(source).0, Kind::Identifier, "lexing `{source}`");
+    }
+
+    assert_eq!(
+        lex("true"),
+        (Kind::BooleanLiteral, Some(Value::Boolean(true)))
+    );
+}
+
+#[test]
+fn only_sized_numeric_types_are_suffixes() {
+    for &(keyword, _, primitive) in PRIMITIVE_TYPES {
+        let sized = primitive.is_float() && primitive != PrimitiveType::Float
+            || primitive.integer_range().is_some();
+
+        assert_eq!(
+            suffix_primitive_type(keyword),
+            sized.then_some(primitive),
+            "suffix `{keyword}`"
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! Lexing of numeric literals, and diagnostics for malformed ones.
+
+mod common;
+
+use tungsten_lexer::{Kind, PrimitiveType, Value};
+
+/// Value of the single token of `source`, which must lex without diagnostics
+fn value(source: &str) -> Value {
+    let (tokens, diagnostics) = common::lex(source);
+    assert_eq!(diagnostics, Vec::<String>::new(), "lexing `{source}`");
+    assert_eq!(tokens.len(), 1, "lexing `{source}`: {tokens:?}");
+
+    tokens[0].value.clone().unwrap()
+}
+
+#[test]
+fn radix_literals() {
+    let cases = [
+        ("0xff", 0xff),
+        ("0XDead_Beef", 0xdead_beef),
+        ("0o17", 0o17),
+        ("0O7_7", 0o77),
+        ("0b1010", 0b1010),
+        ("0B1_0_1", 0b101),
+        ("0x_1", 1),
+        ("1_000_000", 1_000_000),
+        ("0", 0),
+    ];
+
+    for (source, expected) in cases {
+        assert_eq!(
+            value(source),
+            Value::Integer(expected, None),
+            "lexing `{source}`"
+        );
+    }
+
+    // `0..` is a range from zero, not a float
+    assert_eq!(
+        common::kinds("0..2"),
+        vec![
+            (Kind::IntegerLiteral, "0"),
+            (Kind::DoublePeriod, ".."),
+            (Kind::IntegerLiteral, "2"),
+        ]
+    );
+}
+
+#[test]
+fn invalid_radix_literals() {
+    let cases = [
+        ("0b12", "E012 [\"2\"]", 1),
+        ("0o78", "E012 [\"8\"]", 7),
+        ("0xfg", "E012 [\"g\"]", 0xf),
+        ("0b", "E013 [\"0b\"]", 0),
+        ("0x;", "E013 [\"0x\"]", 0),
+    ];
+
+    for (source, diagnostic, recovered) in cases {
+        let (tokens, diagnostics) = common::lex(source);
+
+        assert_eq!(diagnostics, vec![diagnostic], "lexing `{source}`");
+        assert_eq!(
+            tokens[0].value,
+            Some(Value::Integer(recovered, None)),
+            "lexing `{source}`"
+        );
+    }
+
+    // The invalid digits and anything following them belong to the literal
+    let source = "0b12ab c";
+    assert_eq!(
+        common::lexemes(source, &common::lex(source).0),
+        vec![(Kind::IntegerLiteral, "0b12ab"), (Kind::Identifier, "c")]
+    );
+    let source = "0x;";
+    assert_eq!(
+        common::lexemes(source, &common::lex(source).0),
+        vec![(Kind::IntegerLiteral, "0x"), (Kind::Semicolon, ";")]
+    );
+
+    // Radix literals are integers, their fractional part belongs to the literal
+    let cases = [
+        ("0x1.5", "E036 [\".5\"]"),
+        ("0b1.01e2", "E036 [\".01e2\"]"),
+        ("0o7.0", "E036 [\".0\"]"),
+    ];
+    for (source, diagnostic) in cases {
+        let (tokens, diagnostics) = common::lex(source);
+
+        assert_eq!(diagnostics, vec![diagnostic], "lexing `{source}`");
+        assert_eq!(
+            common::lexemes(source, &tokens),
+            vec![(Kind::IntegerLiteral, source)],
+            "lexing `{source}`"
+        );
+    }
+    let source = "0x1..5";
+    assert_eq!(
+        common::lexemes(source, &common::lex(source).0),
+        vec![
+            (Kind::IntegerLiteral, "0x1"),
+            (Kind::DoublePeriod, ".."),
+            (Kind::IntegerLiteral, "5"),
+        ]
+    );
+}
+
+#[test]
+fn out_of_range_and_malformed_literals() {
+    let cases = [
+        ("18446744073709551615", vec![]),
+        (
+            "18446744073709551616",
+            vec!["E014 [\"18446744073709551616\"]"],
+        ),
+        ("1e308", vec![]),
+        ("1e309", vec!["E015 [\"1e309\"]"]),
+        (".5e999", vec!["E015 [\".5e999\"]"]),
+    ];
+
+    for (source, expected) in cases {
+        let (tokens, diagnostics) = common::lex(source);
+
+        assert_eq!(diagnostics, expected, "lexing `{source}`");
+        assert_eq!(tokens.len(), 1, "lexing `{source}`: {tokens:?}");
+        assert_eq!(&source[tokens[0].span.range()], source);
+    }
+
+    assert_eq!(
+        value("18446744073709551615"),
+        Value::Integer(u64::MAX, None)
+    );
+    assert_eq!(
+        common::lex("18446744073709551616").0[0].value,
+        Some(Value::Integer(u64::MAX, None)),
+        "out of range integers saturate"
+    );
+}
+
+#[test]
+fn malformed_literals_become_illegal_tokens() {
+    let cases = [
+        ("1e", vec![(Kind::Illegal, "1e")], "E005 [\"1e\"]"),
+        (
+            "1e+ x",
+            vec![(Kind::Illegal, "1e+"), (Kind::Identifier, "x")],
+            "E004 [\"+\"]",
+        ),
+        (
+            "0b1_ y",
+            vec![(Kind::Illegal, "0b1_"), (Kind::Identifier, "y")],
+            "E004 [\"_\"]",
+        ),
+        (
+            "0.5e; z",
+            vec![
+                (Kind::Illegal, "0.5e"),
+                (Kind::Semicolon, ";"),
+                (Kind::Identifier, "z"),
+            ],
+            "E004 [\"e\"]",
+        ),
+    ];
+
+    for (source, expected, diagnostic) in cases {
+        let (tokens, diagnostics) = common::lex(source);
+
+        assert_eq!(
+            common::lexemes(source, &tokens),
+            expected,
+            "lexing `{source}`"
+        );
+        assert_eq!(diagnostics, vec![diagnostic], "lexing `{source}`");
+    }
+}
+
+#[test]
+fn typed_suffixes() {
+    let cases = [
+        ("10u8", Value::Integer(10, Some(PrimitiveType::U8))),
+        ("3i32", Value::Integer(3, Some(PrimitiveType::I32))),
+        ("0xFFu16", Value::Integer(255, Some(PrimitiveType::U16))),
+        ("0b1i64", Value::Integer(1, Some(PrimitiveType::I64))),
+        ("1_000u64", Value::Integer(1000, Some(PrimitiveType::U64))),
+        ("1_000_u16", Value::Integer(1000, Some(PrimitiveType::U16))),
+        ("0xff_u8", Value::Integer(255, Some(PrimitiveType::U8))),
+        ("2.5_f64", Value::Float(2.5, Some(PrimitiveType::F64))),
+        ("1e3_f32", Value::Float(1000.0, Some(PrimitiveType::F32))),
+        ("1.5f32", Value::Float(1.5, Some(PrimitiveType::F32))),
+        ("2f64", Value::Float(2.0, Some(PrimitiveType::F64))),
+        ("1e3f32", Value::Float(1000.0, Some(PrimitiveType::F32))),
+        ("7", Value::Integer(7, None)),
+        ("0.25", Value::Float(0.25, None)),
+    ];
+
+    for (source, expected) in cases {
+        assert_eq!(value(source), expected, "lexing `{source}`");
+    }
+
+    // Hexadecimal digits take precedence over a float suffix
+    assert_eq!(value("0xF32"), Value::Integer(0xF32, None));
+}
+
+#[test]
+fn invalid_and_mismatched_suffixes() {
+    let cases = [
+        ("10q", "E017 [\"q\"]"),
+        ("10u128", "E017 [\"u128\"]"),
+        ("10int", "E017 [\"int\"]"),
+        ("1.5u8", "E018 [\"u8\"]"),
+        ("0b1f32", "E018 [\"f32\"]"),
+        ("0o7f64", "E018 [\"f64\"]"),
+    ];
+
+    for (source, diagnostic) in cases {
+        let (tokens, diagnostics) = common::lex(source);
+
+        assert_eq!(diagnostics, vec![diagnostic], "lexing `{source}`");
+        assert_eq!(tokens.len(), 1, "lexing `{source}`: {tokens:?}");
+        assert_eq!(&source[tokens[0].span.range()], source);
+    }
+
+    // The suffix is dropped, keeping the literal
+    assert_eq!(
+        common::lex("1.5u8").0[0].value,
+        Some(Value::Float(1.5, None))
+    );
+}
+
+#[test]
+fn suffix_ranges() {
+    let valid = [
+        "255u8",
+        "127i8",
+        "65535u16",
+        "32767i16",
+        "4294967295u32",
+        "18446744073709551615u64",
+        "9223372036854775807i64",
+        "3.4e38f32",
+        "1e308f64",
+    ];
+    for source in valid {
+        value(source);
+    }
+
+    let cases = [
+        ("256u8", "E019 [\"256u8\"]"),
+        ("1_000_u8", "E019 [\"1_000_u8\"]"),
+        ("129i8", "E019 [\"129i8\"]"),
+        ("65536u16", "E019 [\"65536u16\"]"),
+        ("32769i16", "E019 [\"32769i16\"]"),
+        ("0x1_0000_0000u32", "E019 [\"0x1_0000_0000u32\"]"),
+        (
+            "9223372036854775809i64",
+            "E019 [\"9223372036854775809i64\"]",
+        ),
+        ("1e39f32", "E019 [\"1e39f32\"]"),
+    ];
+    for (source, diagnostic) in cases {
+        assert_eq!(common::lex(source).1, vec![diagnostic], "lexing `{source}`");
+    }
+
+    // Only valid negated, which the parser checks
+    assert_eq!(value("128i8"), Value::Integer(128, Some(PrimitiveType::I8)));
+    assert_eq!(
+        value("9223372036854775808i64"),
+        Value::Integer(1 << 63, Some(PrimitiveType::I64))
+    );
+}
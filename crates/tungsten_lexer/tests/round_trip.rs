@@ -0,0 +1,92 @@
+//! Lexing with trivia preserved must reproduce the source byte-for-byte, for every `.tung`
+//! fixture in the repository and every prefix of it, with every token at the position the line
+//! index gives for its start.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+mod common;
+
+use tungsten_context::{ColumnEncoding, LineIndex};
+use tungsten_lexer::{Kind, Lexer, Position};
+
+/// Sources exercising trivia the fixtures may not contain
+const EDGE_CASES: &[&str] = &[
+    "",
+    "   \t ",
+    "a\r\nb\rc\n\n",
+    "# comment\n## doc\nx #[ block #[ nested ]# ]# y",
+    "#[ unterminated",
+    "\"cut {x\n\"a {b} c\"",
+    "var é = 1",
+    "\"\"\"\n  multi\n  line\n  \"\"\" x\n\ny",
+];
+
+fn round_trip(source: &str) -> String {
+    let mut ctx = common::context(source);
+    let file = ctx.main_file();
+    let mut lexer = Lexer::new(&mut ctx, file, source);
+    lexer.set_preserve_trivia(true);
+
+    let tokens = lexer.tokenize();
+    assert_eq!(
+        tokens
+            .iter()
+            .filter(|token| token.kind == Kind::Eof)
+            .count(),
+        1,
+        "expected a single `Eof` token for {source:?}"
+    );
+
+    let line_index = LineIndex::new(source);
+    for token in &tokens {
+        let line_col = line_index.line_col(token.span.start, ColumnEncoding::Utf8);
+        assert_eq!(
+            token.position,
+            Position::new(line_col.line, line_col.column),
+            "position of {token:?} in {source:?}"
+        );
+    }
+
+    tokens.iter().map(|token| token.lexeme).collect()
+}
+
+fn fixtures(dir: &Path, found: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_string_lossy();
+
+        if path.is_dir() && !name.starts_with('.') && name != "target" {
+            fixtures(&path, found);
+        } else if path.extension().is_some_and(|ext| ext == "tung") {
+            found.push(path);
+        }
+    }
+}
+
+#[test]
+fn fixtures_round_trip() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    let mut paths = Vec::new();
+    fixtures(&root, &mut paths);
+
+    assert!(!paths.is_empty(), "no `.tung` fixtures found");
+
+    for path in paths {
+        let source = fs::read_to_string(&path).unwrap();
+
+        for (end, _) in source.char_indices().chain([(source.len(), ' ')]) {
+            let prefix = &source[..end];
+            assert_eq!(round_trip(prefix), prefix, "{}[..{end}]", path.display());
+        }
+    }
+}
+
+#[test]
+fn edge_cases_round_trip() {
+    for source in EDGE_CASES {
+        assert_eq!(&round_trip(source), source);
+    }
+}
@@ -0,0 +1,184 @@
+//! Lexing of string and character literals, and recovery from malformed ones.
+
+mod common;
+
+use tungsten_lexer::{Kind, Value};
+
+fn string(value: &str) -> Option<Value> {
+    Some(Value::String(value.into()))
+}
+
+#[test]
+fn escape_sequences() {
+    let (tokens, diagnostics) = common::lex(r#""\t\"\\\{\}\x41\u0042\u{1F600}\u{43}""#);
+
+    assert_eq!(diagnostics, Vec::<String>::new());
+    assert_eq!(tokens[0].value, string("\t\"\\{}AB\u{1F600}C"));
+}
+
+#[test]
+fn invalid_escapes_keep_the_literal_whole() {
+    let cases = [
+        (r#""\xZZ rest" x"#, "ZZ rest", r#"E003 ["\\x"]"#),
+        (r#""\u{D800}" y"#, "", r#"E031 ["\\u{D800}"]"#),
+        (r#""\u{110000}" z"#, "", r#"E006 ["\\u{110000}"]"#),
+        (r#""a\u{41 b" w"#, "a b", r#"E003 ["\\u{41"]"#),
+        (r#""\u12" v"#, "", r#"E003 ["\\u12"]"#),
+        (r#""\q\é" u"#, "", r#"E003 ["\\q"]"#),
+    ];
+
+    for (source, value, diagnostic) in cases {
+        let (tokens, diagnostics) = common::lex(source);
+
+        assert_eq!(tokens.len(), 2, "lexing `{source}`: {tokens:?}");
+        assert_eq!(tokens[0].kind, Kind::StringLiteral, "lexing `{source}`");
+        assert_eq!(tokens[0].value, string(value), "lexing `{source}`");
+        assert_eq!(tokens[1].kind, Kind::Identifier, "lexing `{source}`");
+        assert_eq!(diagnostics[0], diagnostic, "lexing `{source}`");
+    }
+}
+
+#[test]
+fn char_literals() {
+    let cases = [
+        ("'a'", 'a'),
+        ("'é'", 'é'),
+        ("'\\n'", '\n'),
+        ("'\\''", '\''),
+        ("'\"'", '"'),
+        ("'\\\\'", '\\'),
+        ("'\\x41'", 'A'),
+        ("'\\u{1F600}'", '\u{1F600}'),
+    ];
+
+    for (source, expected) in cases {
+        let (tokens, diagnostics) = common::lex(source);
+
+        assert_eq!(diagnostics, Vec::<String>::new(), "lexing `{source}`");
+        assert_eq!(tokens.len(), 1, "lexing `{source}`: {tokens:?}");
+        assert_eq!(tokens[0].kind, Kind::CharLiteral, "lexing `{source}`");
+        assert_eq!(tokens[0].value, Some(Value::Character(expected)));
+    }
+}
+
+#[test]
+fn malformed_char_literals() {
+    let cases = [
+        ("'' x", "''", r#"E021 ["''"]"#),
+        ("'ab' x", "'ab'", r#"E022 ["'ab'"]"#),
+        ("'\\n\\t' x", "'\\n\\t'", r#"E022 ["'\\n\\t'"]"#),
+        // The invalid escape stands for the character, so it is the only diagnostic
+        ("'\\q' x", "'\\q'", r#"E003 ["\\q"]"#),
+        // Unterminated literals stop at the end of the line
+        ("'a\nx", "'a", r#"E020 ["'a"]"#),
+        ("'\r\nx", "'", r#"E020 ["'"]"#),
+    ];
+
+    for (source, lexeme, diagnostic) in cases {
+        let (tokens, diagnostics) = common::lex(source);
+
+        assert_eq!(diagnostics, vec![diagnostic], "lexing `{source}`");
+        assert_eq!(tokens.len(), 2, "lexing `{source}`: {tokens:?}");
+        assert_eq!(
+            common::lexemes(source, &tokens)[0],
+            (Kind::CharLiteral, lexeme),
+            "lexing `{source}`"
+        );
+        assert_eq!(
+            tokens[0].value,
+            Some(Value::Character(char::REPLACEMENT_CHARACTER)),
+            "lexing `{source}`"
+        );
+        assert_eq!(tokens[1].kind, Kind::Identifier, "lexing `{source}`");
+    }
+
+    // Even within an escape, the end of the file leaves the literal unterminated
+    for source in ["'a", "'\\", "'\\'"] {
+        assert_eq!(
+            common::lex(source).1,
+            vec![format!("E020 [{source:?}]")],
+            "lexing `{source}`"
+        );
+    }
+}
+
+#[test]
+fn raw_strings() {
+    let cases = [
+        (r#"r"a\n{b}""#, r"a\n{b}"),
+        (r##"r#"say "hi""#"##, r#"say "hi""#),
+        (r###"r##"a"#b"##"###, r##"a"#b"##),
+        (r#"r"""#, ""),
+        ("r\"line\r\nbreak\"", "line\r\nbreak"),
+    ];
+
+    for (source, expected) in cases {
+        let (tokens, diagnostics) = common::lex(source);
+
+        assert_eq!(diagnostics, Vec::<String>::new(), "lexing `{source}`");
+        assert_eq!(tokens.len(), 1, "lexing `{source}`: {tokens:?}");
+        assert_eq!(tokens[0].value, string(expected), "lexing `{source}`");
+    }
+
+    // Without a quote, `r` is an identifier
+    assert_eq!(common::lex("r#x").0[0].kind, Kind::Identifier);
+}
+
+#[test]
+fn unterminated_raw_strings() {
+    // Too few hashes do not close the literal, which runs to the end of the file
+    let cases = [
+        (r#"r"open"#, r#"E023 ["r\""]"#),
+        (r##"r#"open" x"##, r##"E023 ["r#\""]"##),
+        (r###"r##"open"#"###, r###"E023 ["r##\""]"###),
+    ];
+
+    for (source, diagnostic) in cases {
+        let (tokens, diagnostics) = common::lex(source);
+
+        assert_eq!(diagnostics, vec![diagnostic], "lexing `{source}`");
+        assert_eq!(tokens.len(), 1, "lexing `{source}`: {tokens:?}");
+        assert_eq!(common::lexemes(source, &tokens)[0].1, source);
+    }
+}
+
+#[test]
+fn multiline_strings() {
+    let cases = [
+        // The closing delimiter's indentation is stripped from every line
+        ("\"\"\"\n    a\n      b\n    \"\"\"", "a\n  b"),
+        ("\"\"\"\n\ta\n\t\"\"\"", "a"),
+        ("\"\"\"\r\n  a\r\n  b\r\n  \"\"\"", "a\nb"),
+        // Lines indented less than the closing delimiter keep what they have
+        ("\"\"\"\n  a\n b\n  \"\"\"", "a\nb"),
+        ("\"\"\"\n  a\n\n  b\n  \"\"\"", "a\n\nb"),
+        // Otherwise, the text is kept as is
+        ("\"\"\"a\n  b\"\"\"", "a\n  b"),
+        ("\"\"\"\n  a\n  b\"\"\"", "  a\n  b"),
+        // Escapes are processed, braces are literal
+        ("\"\"\"\n  \\t{x} \\\"\"\"\n  \"\"\"", "\t{x} \"\"\""),
+        ("\"\"\"\"\"\"", ""),
+    ];
+
+    for (source, expected) in cases {
+        let (tokens, diagnostics) = common::lex(source);
+
+        assert_eq!(diagnostics, Vec::<String>::new(), "lexing `{source}`");
+        assert_eq!(tokens.len(), 1, "lexing `{source}`: {tokens:?}");
+        assert_eq!(tokens[0].value, string(expected), "lexing `{source}`");
+    }
+}
+
+#[test]
+fn unterminated_multiline_strings() {
+    // Pointing at the opening `"""`, not the end of the file
+    for source in ["x \"\"\"\n  open\n", "\"\"\"a\"\"", "\"\"\"a\\"] {
+        let (tokens, diagnostics) = common::lex(source);
+
+        assert_eq!(diagnostics, vec![r#"E024 ["\"\"\""]"#], "lexing `{source}`");
+        assert_eq!(
+            tokens.last().map(|token| token.kind),
+            Some(Kind::StringLiteral)
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! Lookahead, checkpoints and the end of a token stream.
+
+mod common;
+
+use tungsten_lexer::{Kind, Lexer, Token, TokenStream};
+
+/// Tokens of `source`, without the final `Kind::Eof` if `with_eof` is unset
+fn tokens(source: &str, with_eof: bool) -> Vec<Token<'_>> {
+    let mut ctx = common::context(source);
+    let file = ctx.main_file();
+    let mut tokens = Lexer::new(&mut ctx, file, source).tokenize();
+
+    if !with_eof {
+        assert_eq!(tokens.pop().map(|token| token.kind), Some(Kind::Eof));
+    }
+
+    tokens
+}
+
+fn lexemes<'src>(
+    stream: &mut TokenStream<'src, impl Iterator<Item = Token<'src>>>,
+) -> Vec<&'src str> {
+    let mut lexemes = Vec::new();
+    while !stream.is_at_eof() {
+        lexemes.push(stream.advance().lexeme);
+    }
+
+    lexemes
+}
+
+#[test]
+fn peeking_past_the_end_yields_eof() {
+    let source = "a b";
+    let mut stream = TokenStream::new(tokens(source, true));
+
+    assert_eq!(stream.peek_nth(1).lexeme, "b");
+    assert_eq!(stream.peek_nth(2).kind, Kind::Eof);
+    assert_eq!(stream.peek_nth(100).kind, Kind::Eof);
+    // Peeking consumes nothing
+    assert_eq!(stream.peek().lexeme, "a");
+    assert_eq!(stream.position(), 0);
+
+    assert_eq!(lexemes(&mut stream), vec!["a", "b"]);
+
+    // Then `Eof` is returned forever, at the same place
+    let eof = stream.advance();
+    assert_eq!(eof.kind, Kind::Eof);
+    assert_eq!(eof.span.range(), 3..3);
+    assert_eq!(stream.advance(), eof);
+    assert_eq!(stream.peek_nth(5), &eof);
+    assert_eq!(stream.position(), 2);
+}
+
+#[test]
+fn eof_is_synthesized_after_the_last_token() {
+    let source = "ab\n  cd";
+    let mut stream = TokenStream::new(tokens(source, false));
+
+    assert_eq!(stream.peek_nth(2).kind, Kind::Eof);
+    assert_eq!(lexemes(&mut stream), vec!["ab", "cd"]);
+
+    let eof = stream.advance();
+    assert_eq!(eof.kind, Kind::Eof);
+    assert_eq!(eof.lexeme, "");
+    assert_eq!(eof.span.range(), source.len()..source.len());
+    assert_eq!(stream.advance(), eof);
+
+    // Even without any token to place it after
+    let mut stream = TokenStream::new(Vec::new());
+    assert_eq!(stream.advance().kind, Kind::Eof);
+    assert_eq!(stream.peek().span.range(), 0..0);
+}
+
+#[test]
+fn rewinding_to_a_checkpoint() {
+    let mut stream = TokenStream::new(tokens("a b c d", true));
+    stream.advance();
+
+    let checkpoint = stream.checkpoint();
+    assert_eq!(stream.advance().lexeme, "b");
+    assert_eq!(stream.advance().lexeme, "c");
+
+    stream.rewind(checkpoint);
+    assert_eq!(stream.position(), 1);
+    assert_eq!(lexemes(&mut stream), vec!["b", "c", "d"]);
+}
+
+#[test]
+fn nested_checkpoints() {
+    let mut stream = TokenStream::new(tokens("a b c d e", true));
+
+    // An inner commit keeps the tokens for the outer checkpoint to rewind
+    let outer = stream.checkpoint();
+    stream.advance();
+    let inner = stream.checkpoint();
+    stream.advance();
+    stream.advance();
+    stream.commit(inner);
+    assert_eq!(stream.peek().lexeme, "d");
+
+    stream.rewind(outer);
+    assert_eq!(stream.peek().lexeme, "a");
+
+    // An inner rewind only goes back to the inner checkpoint
+    let outer = stream.checkpoint();
+    stream.advance();
+    let inner = stream.checkpoint();
+    stream.advance();
+    stream.rewind(inner);
+    assert_eq!(stream.peek().lexeme, "b");
+    stream.advance();
+    stream.commit(outer);
+    assert_eq!(stream.peek().lexeme, "c");
+
+    // Rewinding the outer checkpoint discards the inner one with it
+    let outer = stream.checkpoint();
+    stream.advance();
+    let _inner = stream.checkpoint();
+    stream.advance();
+    stream.rewind(outer);
+    assert_eq!(lexemes(&mut stream), vec!["c", "d", "e"]);
+}
+
+#[test]
+fn checkpoints_across_the_end() {
+    let mut stream = TokenStream::new(tokens("a", false));
+
+    let checkpoint = stream.checkpoint();
+    assert_eq!(stream.advance().lexeme, "a");
+    assert_eq!(stream.advance().kind, Kind::Eof);
+    assert_eq!(stream.advance().kind, Kind::Eof);
+
+    stream.rewind(checkpoint);
+    assert_eq!(stream.peek().lexeme, "a");
+    assert_eq!(stream.peek_nth(1).kind, Kind::Eof);
+}
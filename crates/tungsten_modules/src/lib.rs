@@ -0,0 +1,5 @@
+pub use loader::*;
+pub use module_tree::*;
+
+mod loader;
+mod module_tree;
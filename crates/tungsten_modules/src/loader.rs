@@ -0,0 +1,226 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use tungsten_ast::{ItemKind, Program};
+use tungsten_context::{error_builders, CompilerContext, FileId, Span};
+use tungsten_lexer::Lexer;
+use tungsten_parser::Parser;
+use tungsten_utils::{atom, Atom};
+
+use crate::{ModuleId, ModuleImport, ModuleTree};
+
+/// Extension of Tungsten source files
+const EXTENSION: &str = "tung";
+
+/// Loads the main file of a context and every module it declares or imports, transitively.
+///
+/// The module `a::b` is read from `a/b.tung`, relative to the directory of the main file, which is
+/// the root module. `module b;` in the module `a` declares that same module. Each file is loaded
+/// once however many times it is imported.
+#[derive(Debug)]
+pub struct ModuleLoader<'a, 'ctx> {
+    context: &'a mut CompilerContext<'ctx>,
+    root_dir: PathBuf,
+    /// Loaded modules by canonical file path
+    loaded: HashMap<PathBuf, ModuleId>,
+    /// Loaded modules whose declarations and imports are yet to be processed
+    pending: VecDeque<ModuleId>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VisitState {
+    Unvisited,
+    InProgress,
+    Done,
+}
+
+impl<'a, 'ctx> ModuleLoader<'a, 'ctx> {
+    pub fn new(context: &'a mut CompilerContext<'ctx>) -> Self {
+        let root_dir = context
+            .path()
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        Self {
+            context,
+            root_dir,
+            loaded: HashMap::new(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Loads the module tree, reporting unresolved modules and import cycles to the context
+    pub fn load(mut self) -> ModuleTree {
+        let root_file = self.context.main_file();
+        let root_path = self.context.path();
+        let root_name = root_path
+            .file_stem()
+            .map_or_else(|| atom!("main"), |stem| atom!(stem.to_string_lossy()));
+
+        let program = self.parse(root_file);
+        let mut tree = ModuleTree::new(root_name, root_file, program);
+
+        self.loaded.insert(canonical(&root_path), tree.root());
+        self.pending.push_back(tree.root());
+
+        while let Some(module) = self.pending.pop_front() {
+            self.load_dependencies(&mut tree, module);
+        }
+
+        self.check_import_cycles(&tree);
+
+        tree
+    }
+
+    /// Parses `file`. Tokens borrow their lexemes from a handle on its source rather than from
+    /// the context, which the lexer and parser report diagnostics to.
+    fn parse(&mut self, file: FileId) -> Program {
+        let source = self.context.sources().get(file).text();
+        let tokens = Lexer::new(self.context, file, &source).tokenize();
+
+        Parser::new(self.context, tokens).parse()
+    }
+
+    /// Loads the modules declared and imported by `module`
+    fn load_dependencies(&mut self, tree: &mut ModuleTree, module: ModuleId) {
+        let Some(program) = tree.get_mut(module).program.take() else {
+            return;
+        };
+
+        for item in &program.items {
+            match &item.kind {
+                ItemKind::Module(name) => {
+                    let mut path = tree.get(module).path.clone();
+                    path.push(name.name.clone());
+
+                    self.load_module(tree, &path, name.span);
+                }
+                ItemKind::Import(import) => {
+                    let path: Vec<Atom> = import
+                        .segments
+                        .iter()
+                        .map(|segment| segment.name.clone())
+                        .collect();
+
+                    if let Some(imported) = self.load_module(tree, &path, import.span) {
+                        tree.get_mut(module).imports.push(ModuleImport {
+                            module: imported,
+                            span: import.span,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        tree.get_mut(module).program = Some(program);
+    }
+
+    /// Returns the module at `path`, loading its file unless it already was. `span` is where the
+    /// module is referred to, for diagnostics.
+    fn load_module(
+        &mut self,
+        tree: &mut ModuleTree,
+        path: &[Atom],
+        span: Span,
+    ) -> Option<ModuleId> {
+        let mut file_path = self.root_dir.clone();
+        file_path.extend(path.iter().map(|segment| &**segment));
+        file_path.set_extension(EXTENSION);
+
+        let canonical_path = canonical(&file_path);
+        if let Some(&module) = self.loaded.get(&canonical_path) {
+            return Some(module);
+        }
+
+        let source = match fs::read_to_string(&file_path) {
+            Ok(source) => source,
+            Err(err) => {
+                let module = path.iter().map(|segment| &**segment).collect::<Vec<_>>();
+                self.context
+                    .add_error(error_builders::build_unresolved_module_error(
+                        span,
+                        &module.join("::"),
+                        &file_path.display().to_string(),
+                        &err.to_string(),
+                    ));
+
+                return None;
+            }
+        };
+
+        let file = self.context.add_file(file_path, source);
+        let program = self.parse(file);
+
+        let module = tree.get_or_insert(path);
+        let entry = tree.get_mut(module);
+        entry.file = Some(file);
+        entry.program = Some(program);
+
+        self.loaded.insert(canonical_path, module);
+        self.pending.push_back(module);
+
+        Some(module)
+    }
+
+    /// Reports every import cycle, walking the imports depth first from each module
+    fn check_import_cycles(&mut self, tree: &ModuleTree) {
+        let mut states = vec![VisitState::Unvisited; tree.iter().count()];
+
+        for (module, _) in tree.iter() {
+            let mut stack = Vec::new();
+            self.visit_imports(tree, module, &mut states, &mut stack);
+        }
+    }
+
+    /// `stack` holds the modules being visited, each with the import leading to the next one
+    fn visit_imports(
+        &mut self,
+        tree: &ModuleTree,
+        module: ModuleId,
+        states: &mut [VisitState],
+        stack: &mut Vec<(ModuleId, Span)>,
+    ) {
+        if states[module.0] != VisitState::Unvisited {
+            return;
+        }
+        states[module.0] = VisitState::InProgress;
+
+        for import in &tree.get(module).imports {
+            stack.push((module, import.span));
+
+            if states[import.module.0] == VisitState::InProgress {
+                let start = stack
+                    .iter()
+                    .position(|&(visited, _)| visited == import.module)
+                    .expect("module in progress is on the stack");
+
+                let mut cycle: Vec<String> = stack[start..]
+                    .iter()
+                    .map(|&(visited, _)| tree.display_path(visited))
+                    .collect();
+                cycle.push(tree.display_path(import.module));
+
+                let imports: Vec<Span> = stack[start..].iter().map(|&(_, span)| span).collect();
+
+                self.context
+                    .add_error(error_builders::build_import_cycle_error(&cycle, &imports));
+            } else {
+                self.visit_imports(tree, import.module, states, stack);
+            }
+
+            stack.pop();
+        }
+
+        states[module.0] = VisitState::Done;
+    }
+}
+
+/// Canonical form of `path` identifying a file, or `path` itself if it cannot be resolved
+fn canonical(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
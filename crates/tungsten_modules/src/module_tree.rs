@@ -0,0 +1,135 @@
+use tungsten_ast::Program;
+use tungsten_context::{FileId, Span};
+use tungsten_utils::Atom;
+
+/// Identifies a module of a [`ModuleTree`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModuleId(pub(crate) usize);
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    /// Last segment of the module path, the root module is named after its file
+    pub name: Atom,
+    /// Segments of the path from the root module, empty for the root module itself
+    pub path: Vec<Atom>,
+    /// File the module was loaded from, `None` for a module only made of submodules
+    pub file: Option<FileId>,
+    pub program: Option<Program>,
+    pub parent: Option<ModuleId>,
+    pub children: Vec<ModuleId>,
+    pub imports: Vec<ModuleImport>,
+}
+
+/// `import` of a module by another
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModuleImport {
+    pub module: ModuleId,
+    /// Span of the imported path
+    pub span: Span,
+}
+
+/// Modules of a compilation, the children of a module being its submodules
+#[derive(Debug, Clone)]
+pub struct ModuleTree {
+    modules: Vec<Module>,
+}
+
+impl ModuleTree {
+    pub(crate) fn new(root_name: Atom, root_file: FileId, program: Program) -> Self {
+        let mut root = Module::new(root_name, Vec::new(), None);
+        root.file = Some(root_file);
+        root.program = Some(program);
+
+        Self {
+            modules: vec![root],
+        }
+    }
+
+    pub fn root(&self) -> ModuleId {
+        ModuleId(0)
+    }
+
+    /// Returns the module `id`, which must come from this tree
+    pub fn get(&self, id: ModuleId) -> &Module {
+        &self.modules[id.0]
+    }
+
+    pub(crate) fn get_mut(&mut self, id: ModuleId) -> &mut Module {
+        &mut self.modules[id.0]
+    }
+
+    /// Looks up the module at `path` from the root module
+    pub fn find(&self, path: &[Atom]) -> Option<ModuleId> {
+        path.iter()
+            .try_fold(self.root(), |module, name| self.child(module, name))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (ModuleId, &Module)> {
+        self.modules
+            .iter()
+            .enumerate()
+            .map(|(index, module)| (ModuleId(index), module))
+    }
+
+    /// Path of the module as written in imports, eg: `a::b`, or the name of the root module
+    pub fn display_path(&self, id: ModuleId) -> String {
+        let module = self.get(id);
+
+        if module.path.is_empty() {
+            return module.name.to_string();
+        }
+
+        module
+            .path
+            .iter()
+            .map(|segment| &**segment)
+            .collect::<Vec<_>>()
+            .join("::")
+    }
+
+    /// Returns the module at `path`, adding it and any missing ancestor without a file
+    pub(crate) fn get_or_insert(&mut self, path: &[Atom]) -> ModuleId {
+        let mut module = self.root();
+
+        for (depth, name) in path.iter().enumerate() {
+            module = match self.child(module, name) {
+                Some(child) => child,
+                None => {
+                    let child = ModuleId(self.modules.len());
+                    self.modules.push(Module::new(
+                        name.clone(),
+                        path[..=depth].to_vec(),
+                        Some(module),
+                    ));
+                    self.get_mut(module).children.push(child);
+
+                    child
+                }
+            };
+        }
+
+        module
+    }
+
+    fn child(&self, module: ModuleId, name: &Atom) -> Option<ModuleId> {
+        self.get(module)
+            .children
+            .iter()
+            .copied()
+            .find(|&child| self.get(child).name == *name)
+    }
+}
+
+impl Module {
+    fn new(name: Atom, path: Vec<Atom>, parent: Option<ModuleId>) -> Self {
+        Self {
+            name,
+            path,
+            file: None,
+            program: None,
+            parent,
+            children: Vec::new(),
+            imports: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Module loading from the fixture projects in `tests/fixtures`, each rooted at its `main.tung`.
+
+use std::{fs, path::PathBuf};
+
+use tungsten_context::CompilerContext;
+use tungsten_modules::{ModuleLoader, ModuleTree};
+use tungsten_utils::{atom, Atom};
+
+/// Loads the fixture `name`, returning its module tree and the diagnostic messages and notes
+fn load(name: &str) -> (ModuleTree, Vec<String>) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+        .join("main.tung");
+    let source = fs::read_to_string(&path).unwrap();
+    let artifact_dir = PathBuf::from("target");
+
+    let mut ctx = CompilerContext::new(&path, &source, &artifact_dir);
+    let tree = ModuleLoader::new(&mut ctx).load();
+
+    let errors = ctx
+        .errors()
+        .iter()
+        .map(|error| format!("{}: {}", error.message, error.notes.join("; ")))
+        .collect();
+
+    (tree, errors)
+}
+
+fn path(segments: &[&str]) -> Vec<Atom> {
+    segments.iter().map(|&segment| atom!(segment)).collect()
+}
+
+#[test]
+fn nested_modules() {
+    let (tree, errors) = load("nested");
+    assert_eq!(errors, Vec::<String>::new());
+
+    let util = tree.find(&path(&["util"])).expect("`util` is declared");
+    let math = tree
+        .find(&path(&["util", "math"]))
+        .expect("`util::math` is imported");
+
+    assert_eq!(tree.get(math).parent, Some(util));
+    assert_eq!(tree.get(util).parent, Some(tree.root()));
+    assert!(tree.get(util).file.is_some());
+    assert_eq!(tree.display_path(math), "util::math");
+    assert_eq!(tree.display_path(tree.root()), "main");
+}
+
+#[test]
+fn module_sources_are_kept_by_the_source_map() {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/nested");
+    let main_path = dir.join("main.tung");
+    let source = fs::read_to_string(&main_path).unwrap();
+    let artifact_dir = PathBuf::from("target");
+
+    let mut ctx = CompilerContext::new(&main_path, &source, &artifact_dir);
+    let tree = ModuleLoader::new(&mut ctx).load();
+
+    let math = tree.find(&path(&["util", "math"])).unwrap();
+    let file = ctx.sources().get(tree.get(math).file.unwrap());
+    assert_eq!(file.path(), dir.join("util/math.tung"));
+    assert_eq!(
+        file.source(),
+        fs::read_to_string(dir.join("util/math.tung")).unwrap()
+    );
+}
+
+#[test]
+fn import_cycle() {
+    let (_, errors) = load("cycle");
+
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(errors[0].contains("Cycle: a -> b -> a"), "{errors:?}");
+}
+
+#[test]
+fn missing_module() {
+    let (tree, errors) = load("missing");
+
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(
+        errors[0].starts_with("Cannot find module `nowhere::found`"),
+        "{errors:?}"
+    );
+    assert!(tree.find(&path(&["nowhere"])).is_none());
+}
+
+#[test]
+fn modules_are_loaded_once() {
+    let (tree, errors) = load("shared");
+    assert_eq!(errors, Vec::<String>::new());
+
+    let common = tree.find(&path(&["common"])).unwrap();
+    let importers = tree
+        .iter()
+        .filter(|(_, module)| module.imports.iter().any(|import| import.module == common))
+        .count();
+
+    assert_eq!(importers, 2);
+    assert_eq!(tree.iter().count(), 4);
+}
@@ -0,0 +1,25 @@
+use thiserror::Error;
+use tungsten_context::Span;
+use tungsten_lexer::PrimitiveType;
+
+#[derive(Error, Debug)]
+pub enum ParseError {
+    #[error("expected {expected}, found {found}")]
+    UnexpectedToken {
+        expected: &'static str,
+        found: String,
+        span: Span,
+    },
+
+    #[error("expected expression, found {found}")]
+    ExpectedExpression { found: String, span: Span },
+
+    #[error("operator `{op}` cannot be chained")]
+    NonAssociativeOperator { op: String, span: Span },
+
+    #[error("invalid assignment target")]
+    InvalidAssignmentTarget(Span),
+
+    #[error("literal out of range for `{}`", suffix.as_str())]
+    LiteralOutOfRange { suffix: PrimitiveType, span: Span },
+}
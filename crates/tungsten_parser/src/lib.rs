@@ -0,0 +1,4 @@
+pub use parser::*;
+
+mod errors;
+mod parser;
@@ -0,0 +1,350 @@
+use tungsten_ast::{BinaryOp, Expr, ExprKind, Literal, Span, UnaryOp};
+use tungsten_lexer::{Kind, Value};
+
+use crate::{
+    errors::ParseError,
+    parser::{
+        describe_token,
+        precedence::{infix_entry, Associativity, InfixOperator, Precedence},
+    },
+    Parser,
+};
+
+impl Parser<'_, '_, '_> {
+    pub(crate) fn parse_expression(&mut self) -> Result<Expr, ParseError> {
+        self.parse_expression_with(Precedence::Lowest)
+    }
+
+    /// Parses an expression made of operators binding at least as tightly as `min`
+    pub(crate) fn parse_expression_with(&mut self, min: Precedence) -> Result<Expr, ParseError> {
+        let mut lhs = self.parse_prefix()?;
+        // Level of the last non-associative operator applied, to reject chains like `a < b < c`
+        let mut non_associative = None;
+
+        while let Some(entry) = infix_entry(self.peek_kind()) {
+            if entry.precedence < min {
+                break;
+            }
+
+            if non_associative == Some(entry.precedence) {
+                let token = self.peek();
+
+                return Err(ParseError::NonAssociativeOperator {
+                    op: token.lexeme.to_string(),
+                    span: token.span,
+                });
+            }
+
+            self.advance();
+
+            let rhs_min = match entry.associativity {
+                Associativity::Right => entry.precedence,
+                Associativity::Left | Associativity::None => entry.precedence.tighter(),
+            };
+            let rhs = self.parse_expression_with(rhs_min)?;
+            let span = lhs.span.to(rhs.span);
+
+            let kind = match entry.operator {
+                InfixOperator::Binary(op) => ExprKind::Binary {
+                    op,
+                    lhs: Box::new(lhs),
+                    rhs: Box::new(rhs),
+                },
+                InfixOperator::Assign(op) => {
+                    if !is_assignable(&lhs) {
+                        return Err(ParseError::InvalidAssignmentTarget(lhs.span));
+                    }
+
+                    ExprKind::Assign {
+                        op,
+                        target: Box::new(lhs),
+                        value: Box::new(rhs),
+                    }
+                }
+            };
+
+            lhs = Expr { kind, span };
+            non_associative =
+                (entry.associativity == Associativity::None).then_some(entry.precedence);
+        }
+
+        Ok(lhs)
+    }
+
+    fn parse_prefix(&mut self) -> Result<Expr, ParseError> {
+        let op = match self.peek_kind() {
+            Kind::Dash => UnaryOp::Neg,
+            Kind::Bang => UnaryOp::Not,
+            Kind::Tilde => UnaryOp::BitNot,
+            Kind::DoublePlus => UnaryOp::PreIncrement,
+            Kind::DoubleDash => UnaryOp::PreDecrement,
+            _ => return self.parse_postfix(),
+        };
+
+        let start = self.advance().span.start;
+        // Skip the range check of `parse_literal`, eg: `-128i8` is valid while `128i8` is not
+        let negated_literal = if op == UnaryOp::Neg && self.is_negated_literal_ahead() {
+            let span = self.peek().span;
+            self.read_literal().map(|literal| Expr {
+                kind: ExprKind::Literal(literal),
+                span,
+            })
+        } else {
+            None
+        };
+
+        let operand = match negated_literal {
+            Some(literal) => literal,
+            None => self.parse_expression_with(Precedence::Prefix)?,
+        };
+
+        if matches!(op, UnaryOp::PreIncrement | UnaryOp::PreDecrement) && !is_assignable(&operand) {
+            return Err(ParseError::InvalidAssignmentTarget(operand.span));
+        }
+
+        Ok(Expr {
+            span: Span {
+                start,
+                ..operand.span
+            },
+            kind: ExprKind::Unary {
+                op,
+                operand: Box::new(operand),
+            },
+        })
+    }
+
+    fn parse_postfix(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.parse_primary()?;
+
+        loop {
+            let start = expr.span.start;
+
+            expr = match self.peek_kind() {
+                Kind::LParen => {
+                    self.advance();
+
+                    let mut args = Vec::new();
+                    while !self.at(Kind::RParen) {
+                        args.push(self.parse_expression()?);
+
+                        if !self.eat(Kind::Comma) {
+                            break;
+                        }
+                    }
+                    self.expect(Kind::RParen, "`)`")?;
+
+                    Expr {
+                        kind: ExprKind::Call {
+                            callee: Box::new(expr),
+                            args,
+                        },
+                        span: self.span_from(start),
+                    }
+                }
+                Kind::LBracket => {
+                    self.advance();
+                    let index = self.parse_expression()?;
+                    self.expect(Kind::RBracket, "`]`")?;
+
+                    Expr {
+                        kind: ExprKind::Index {
+                            target: Box::new(expr),
+                            index: Box::new(index),
+                        },
+                        span: self.span_from(start),
+                    }
+                }
+                Kind::DoublePlus | Kind::DoubleDash => {
+                    if !is_assignable(&expr) {
+                        return Err(ParseError::InvalidAssignmentTarget(expr.span));
+                    }
+
+                    let op = match self.advance().kind {
+                        Kind::DoublePlus => UnaryOp::PostIncrement,
+                        _ => UnaryOp::PostDecrement,
+                    };
+
+                    Expr {
+                        kind: ExprKind::Unary {
+                            op,
+                            operand: Box::new(expr),
+                        },
+                        span: self.span_from(start),
+                    }
+                }
+                Kind::Period => {
+                    self.advance();
+                    let member = self.expect_identifier()?;
+
+                    Expr {
+                        kind: ExprKind::Member {
+                            target: Box::new(expr),
+                            member,
+                        },
+                        span: self.span_from(start),
+                    }
+                }
+                _ => return Ok(expr),
+            };
+        }
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, ParseError> {
+        let start = self.peek().span.start;
+
+        if let Some(literal) = self.parse_literal() {
+            return Ok(Expr {
+                kind: ExprKind::Literal(literal),
+                span: self.span_from(start),
+            });
+        }
+
+        match self.peek_kind() {
+            Kind::Identifier => {
+                let ident = self.expect_identifier()?;
+
+                Ok(Expr {
+                    span: ident.span,
+                    kind: ExprKind::Identifier(ident),
+                })
+            }
+            Kind::StringHead => self.parse_interpolated_string(),
+            Kind::LParen => {
+                self.advance();
+                let mut expr = self.parse_expression()?;
+                self.expect(Kind::RParen, "`)`")?;
+
+                // Include the parentheses in the span
+                expr.span = self.span_from(start);
+
+                Ok(expr)
+            }
+            _ => {
+                let token = self.peek();
+
+                Err(ParseError::ExpectedExpression {
+                    found: describe_token(token),
+                    span: token.span,
+                })
+            }
+        }
+    }
+
+    /// Parses `"a {x} b"` into the concatenation `"a " <> x <> " b"`, keeping the head even if
+    /// it is empty so the result is always a string
+    fn parse_interpolated_string(&mut self) -> Result<Expr, ParseError> {
+        let start = self.peek().span.start;
+        let mut expr = self.parse_string_fragment();
+
+        loop {
+            let value = self.parse_expression()?;
+            expr = concat(expr, value);
+
+            let last = match self.peek_kind() {
+                Kind::StringMiddle => false,
+                Kind::StringTail => true,
+                _ => return Err(self.unexpected("`}`")),
+            };
+
+            let fragment = self.parse_string_fragment();
+            if !matches!(&fragment.kind, ExprKind::Literal(Literal::String(text)) if text.is_empty())
+            {
+                expr = concat(expr, fragment);
+            }
+
+            if last {
+                // Cover the whole literal, even when its tail is empty and left out
+                expr.span = self.span_from(start);
+
+                return Ok(expr);
+            }
+        }
+    }
+
+    /// Consumes a string head, middle or tail as a string literal
+    fn parse_string_fragment(&mut self) -> Expr {
+        let token = self.advance();
+        let text = match token.value {
+            Some(Value::String(text)) => text,
+            _ => Default::default(),
+        };
+
+        Expr {
+            kind: ExprKind::Literal(Literal::String(text)),
+            span: token.span,
+        }
+    }
+
+    /// Whether the `-` just consumed directly negates the integer literal ahead, rather than an
+    /// expression the literal is part of, eg: `-2 ** 2`
+    fn is_negated_literal_ahead(&mut self) -> bool {
+        let next = self.peek_nth(1).kind;
+        let binds_tighter = infix_entry(next)
+            .is_some_and(|entry| entry.precedence > Precedence::Prefix)
+            || matches!(
+                next,
+                Kind::LParen | Kind::LBracket | Kind::DoublePlus | Kind::DoubleDash | Kind::Period
+            );
+
+        self.at(Kind::IntegerLiteral) && !binds_tighter
+    }
+
+    /// Consumes the current token if it is a literal, reporting the magnitude of a signed minimum
+    /// which is only valid negated, eg: `128i8`
+    pub(crate) fn parse_literal(&mut self) -> Option<Literal> {
+        let span = self.peek().span;
+        let literal = self.read_literal()?;
+
+        // Larger values are reported by the lexer
+        if let Literal::Integer(value, Some(suffix)) = literal {
+            if suffix
+                .integer_range()
+                .is_some_and(|(min, _)| min < 0 && value as i128 == -min)
+            {
+                let err = ParseError::LiteralOutOfRange { suffix, span };
+                self.report_error(err);
+            }
+        }
+
+        Some(literal)
+    }
+
+    /// Consumes the current token if it is a literal
+    fn read_literal(&mut self) -> Option<Literal> {
+        let literal = match (self.peek_kind(), &self.peek().value) {
+            (Kind::IntegerLiteral, Some(Value::Integer(value, suffix))) => {
+                Literal::Integer(*value, *suffix)
+            }
+            (Kind::FloatLiteral, Some(Value::Float(value, suffix))) => {
+                Literal::Float(*value, *suffix)
+            }
+            (Kind::StringLiteral, Some(Value::String(value))) => Literal::String(value.clone()),
+            (Kind::CharLiteral, Some(Value::Character(value))) => Literal::Character(*value),
+            (Kind::BooleanLiteral, Some(Value::Boolean(value))) => Literal::Boolean(*value),
+            _ => return None,
+        };
+
+        self.advance();
+
+        Some(literal)
+    }
+}
+
+fn concat(lhs: Expr, rhs: Expr) -> Expr {
+    Expr {
+        span: lhs.span.to(rhs.span),
+        kind: ExprKind::Binary {
+            op: BinaryOp::Concat,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+        },
+    }
+}
+
+fn is_assignable(expr: &Expr) -> bool {
+    matches!(
+        expr.kind,
+        ExprKind::Identifier(_) | ExprKind::Index { .. } | ExprKind::Member { .. }
+    )
+}
@@ -0,0 +1,151 @@
+use tungsten_ast::{Function, Item, ItemKind, ModulePath, Param, Type, TypeKind, Visibility};
+use tungsten_context::error_builders;
+use tungsten_lexer::{Kind, Value};
+use tungsten_utils::{atom, Atom};
+
+use crate::{errors::ParseError, Parser};
+
+impl Parser<'_, '_, '_> {
+    /// Parses an item, documented by `doc` if it was preceded by doc comments
+    pub(crate) fn parse_item(&mut self, doc: Option<Atom>) -> Result<Item, ParseError> {
+        let start = self.peek().span.start;
+
+        let visibility = if self.eat(Kind::PubKw) {
+            Visibility::Public
+        } else {
+            Visibility::Private
+        };
+
+        let kind = match self.peek_kind() {
+            Kind::FuncKw => ItemKind::Function(self.parse_function()?),
+            Kind::VarKw | Kind::ConstKw => {
+                let binding = self.parse_binding()?;
+                self.expect(Kind::Semicolon, "`;`")?;
+
+                ItemKind::Binding(binding)
+            }
+            Kind::ModuleKw => {
+                self.advance();
+                let name = self.expect_identifier()?;
+                self.expect(Kind::Semicolon, "`;`")?;
+
+                ItemKind::Module(name)
+            }
+            Kind::ImportKw => {
+                self.advance();
+                let path = self.parse_module_path()?;
+                self.expect(Kind::Semicolon, "`;`")?;
+
+                ItemKind::Import(path)
+            }
+            _ => return Err(self.unexpected("item")),
+        };
+
+        Ok(Item {
+            kind,
+            visibility,
+            doc,
+            span: self.span_from(start),
+        })
+    }
+
+    /// Joins consecutive `##` doc comments into a single text
+    pub(crate) fn parse_doc_comments(&mut self) -> Option<Atom> {
+        let mut lines = Vec::new();
+
+        while self.at(Kind::DocComment) {
+            if let Some(Value::String(line)) = self.advance().value {
+                lines.push(line.to_string());
+            }
+        }
+
+        (!lines.is_empty()).then(|| atom!(lines.join("\n")))
+    }
+
+    /// Warns about the doc comments from `start` to the last consumed token, which document
+    /// nothing
+    pub(crate) fn report_unattached_doc_comment(&mut self, start: usize) {
+        let span = self.span_from(start);
+
+        self.context
+            .add_error(error_builders::build_unattached_doc_comment_warning(span));
+    }
+
+    fn parse_function(&mut self) -> Result<Function, ParseError> {
+        self.expect(Kind::FuncKw, "`func`")?;
+        let name = self.expect_identifier()?;
+
+        self.expect(Kind::LParen, "`(`")?;
+        let mut params = Vec::new();
+        while !self.at(Kind::RParen) {
+            params.push(self.parse_param()?);
+
+            if !self.eat(Kind::Comma) {
+                break;
+            }
+        }
+        self.expect(Kind::RParen, "`)`")?;
+
+        let return_type = if self.eat(Kind::Arrow) {
+            Some(self.parse_type()?)
+        } else {
+            None
+        };
+
+        let body = self.parse_block()?;
+
+        Ok(Function {
+            name,
+            params,
+            return_type,
+            body,
+        })
+    }
+
+    fn parse_module_path(&mut self) -> Result<ModulePath, ParseError> {
+        let start = self.peek().span.start;
+
+        let mut segments = vec![self.expect_identifier()?];
+        while self.eat(Kind::DoubleColon) {
+            segments.push(self.expect_identifier()?);
+        }
+
+        Ok(ModulePath {
+            segments,
+            span: self.span_from(start),
+        })
+    }
+
+    fn parse_param(&mut self) -> Result<Param, ParseError> {
+        let start = self.peek().span.start;
+        let name = self.expect_identifier()?;
+        self.expect(Kind::Colon, "`:`")?;
+        let ty = self.parse_type()?;
+
+        Ok(Param {
+            name,
+            ty,
+            span: self.span_from(start),
+        })
+    }
+
+    pub(crate) fn parse_type(&mut self) -> Result<Type, ParseError> {
+        let start = self.peek().span.start;
+
+        let kind = match self.peek_kind() {
+            Kind::Identifier => TypeKind::Named(self.expect_identifier()?),
+            kind => match kind.primitive_type() {
+                Some(primitive) => {
+                    self.advance();
+                    TypeKind::Primitive(primitive)
+                }
+                None => return Err(self.unexpected("type")),
+            },
+        };
+
+        Ok(Type {
+            kind,
+            span: self.span_from(start),
+        })
+    }
+}
@@ -0,0 +1,210 @@
+use std::vec;
+use tungsten_ast::{Ident, Item, ItemKind, Program, Span, Visibility};
+use tungsten_context::{error_builders, CompilerContext, FileId};
+use tungsten_lexer::{Kind, Token, TokenStream, Value};
+use tungsten_utils::{atom, Atom};
+
+use crate::errors::ParseError;
+
+mod expressions;
+mod items;
+mod precedence;
+mod recovery;
+mod statements;
+
+#[derive(Debug)]
+pub struct Parser<'a, 'ctx, 'src> {
+    pub(crate) context: &'a mut CompilerContext<'ctx>,
+    pub(crate) tokens: TokenStream<'src, vec::IntoIter<Token<'src>>>,
+    /// File the tokens were read from
+    pub(crate) file: FileId,
+    /// End offset of the last consumed token
+    pub(crate) previous_end: usize,
+}
+
+impl<'a, 'ctx, 'src> Parser<'a, 'ctx, 'src> {
+    /// Creates a parser over `tokens`, which should end with the `Kind::Eof` token read by the
+    /// lexer. One is synthesized otherwise. Trivia and illegal tokens, which the lexer has already
+    /// reported, are ignored.
+    pub fn new(context: &'a mut CompilerContext<'ctx>, mut tokens: Vec<Token<'src>>) -> Self {
+        tokens.retain(|token| !token.kind.is_trivia() && token.kind != Kind::Illegal);
+
+        Self {
+            file: tokens
+                .first()
+                .map(|token| token.span.file)
+                .unwrap_or_default(),
+            context,
+            tokens: TokenStream::new(tokens),
+            previous_end: 0,
+        }
+    }
+
+    /// Parses the whole token stream. Syntax errors are reported to the context and replaced by
+    /// error nodes, so the returned program always covers the whole file.
+    pub fn parse(&mut self) -> Program {
+        let start = self.peek().span.start;
+        let mut items = Vec::new();
+
+        while !self.at(Kind::Eof) {
+            let doc_start = self.peek().span.start;
+            let doc = self.parse_doc_comments();
+
+            // A binding is the only statement also valid as an item
+            let documentable = recovery::is_item_start(self.peek_kind())
+                || matches!(self.peek_kind(), Kind::VarKw | Kind::ConstKw);
+
+            if doc.is_some() && !documentable {
+                self.report_unattached_doc_comment(doc_start);
+                continue;
+            }
+
+            let item_start = self.peek().span.start;
+
+            match self.parse_item(doc.clone()) {
+                Ok(item) => items.push(item),
+                Err(err) => {
+                    self.report_error(err);
+                    self.synchronize_item();
+
+                    items.push(Item {
+                        kind: ItemKind::Error,
+                        visibility: Visibility::Private,
+                        doc,
+                        span: self.span_from(item_start),
+                    });
+                }
+            }
+        }
+
+        Program {
+            items,
+            span: self.span_from(start),
+        }
+    }
+
+    pub(crate) fn peek(&mut self) -> &Token<'src> {
+        self.tokens.peek()
+    }
+
+    pub(crate) fn peek_nth(&mut self, n: usize) -> &Token<'src> {
+        self.tokens.peek_nth(n)
+    }
+
+    pub(crate) fn peek_kind(&mut self) -> Kind {
+        self.peek().kind
+    }
+
+    pub(crate) fn at(&mut self, kind: Kind) -> bool {
+        self.peek_kind() == kind
+    }
+
+    pub(crate) fn advance(&mut self) -> Token<'src> {
+        let token = self.tokens.advance();
+
+        if token.kind != Kind::Eof {
+            self.previous_end = token.span.end;
+        }
+
+        token
+    }
+
+    pub(crate) fn eat(&mut self, kind: Kind) -> bool {
+        if self.at(kind) {
+            self.advance();
+            return true;
+        }
+
+        false
+    }
+
+    pub(crate) fn expect(
+        &mut self,
+        kind: Kind,
+        expected: &'static str,
+    ) -> Result<Token<'src>, ParseError> {
+        if self.at(kind) {
+            return Ok(self.advance());
+        }
+
+        Err(self.unexpected(expected))
+    }
+
+    pub(crate) fn expect_identifier(&mut self) -> Result<Ident, ParseError> {
+        let token = self.expect(Kind::Identifier, "identifier")?;
+
+        // The value holds the normalized name, which may differ from the lexeme
+        let name = match token.value {
+            Some(Value::String(name)) => name,
+            _ => atom!(token.lexeme),
+        };
+
+        Ok(Ident {
+            name,
+            span: token.span,
+        })
+    }
+
+    pub(crate) fn unexpected(&mut self, expected: &'static str) -> ParseError {
+        let token = self.peek();
+
+        ParseError::UnexpectedToken {
+            expected,
+            found: describe_token(token),
+            span: token.span,
+        }
+    }
+
+    /// Span from `start` to the end of the last consumed token
+    pub(crate) fn span_from(&self, start: usize) -> Span {
+        Span::new(self.file, start..self.previous_end.max(start))
+    }
+
+    pub(crate) fn report_error(&mut self, err: ParseError) {
+        match err {
+            ParseError::UnexpectedToken {
+                expected,
+                found,
+                span,
+            } => {
+                self.context
+                    .add_error(error_builders::build_unexpected_token_error(
+                        span, expected, &found,
+                    ));
+            }
+            ParseError::ExpectedExpression { found, span } => {
+                self.context
+                    .add_error(error_builders::build_expected_expression_error(
+                        span, &found,
+                    ));
+            }
+            ParseError::NonAssociativeOperator { op, span } => {
+                self.context
+                    .add_error(error_builders::build_non_associative_operator_error(
+                        span, &op,
+                    ));
+            }
+            ParseError::InvalidAssignmentTarget(span) => {
+                self.context
+                    .add_error(error_builders::build_invalid_assignment_target_error(span));
+            }
+            ParseError::LiteralOutOfRange { suffix, span } => {
+                let (min, max) = suffix.integer_range().unwrap_or_default();
+
+                self.context
+                    .add_error(error_builders::build_suffix_out_of_range_error(
+                        span,
+                        suffix.as_str(),
+                        &format!("{min}..={max}"),
+                    ));
+            }
+        }
+    }
+}
+
+pub(crate) fn describe_token(token: &Token) -> String {
+    match token.kind {
+        Kind::Eof => "end of file".to_string(),
+        _ => format!("`{}`", token.lexeme),
+    }
+}
@@ -0,0 +1,279 @@
+//! Operator precedence and associativity for the expression parser.
+//!
+//! Levels from loosest to tightest binding:
+//!
+//! | Level          | Operators                                                  | Associativity |
+//! |----------------|------------------------------------------------------------|---------------|
+//! | Assignment     | `=` `+=` `-=` `*=` `/=` `//=` `%=` `**=` `<<=` `>>=` `&=` `\|=` `^=` | right |
+//! | Range          | `..` `..=`                                                 | none          |
+//! | LogicalOr      | `\|\|`                                                     | left          |
+//! | LogicalAnd     | `&&`                                                       | left          |
+//! | Equality       | `==` `!=`                                                  | none          |
+//! | Comparison     | `<` `<=` `>` `>=`                                          | none          |
+//! | BitwiseOr      | `\|`                                                       | left          |
+//! | BitwiseXor     | `^`                                                        | left          |
+//! | BitwiseAnd     | `&`                                                        | left          |
+//! | Shift          | `<<` `>>`                                                  | left          |
+//! | Concat         | `<>`                                                       | left          |
+//! | Additive       | `+` `-`                                                    | left          |
+//! | Multiplicative | `*` `/` `//` `%`                                           | left          |
+//! | Prefix         | `-a` `!a` `~a` `++a` `--a`                                 | -             |
+//! | Power          | `**`                                                       | right         |
+//! | Postfix        | `a++` `a--` `a(..)` `a[..]` `a.b`                          | -             |
+//!
+//! `**` binds tighter than prefix operators, so `-a ** b` is `-(a ** b)`, while its right operand
+//! may itself be prefixed: `a ** -b`. Non-associative operators cannot be chained without
+//! parentheses, eg: `a < b < c` and `a..b..c` are errors.
+//!
+//! `->` and `=>` are not expression operators: they belong to function signatures and match arms
+//! respectively, and terminate an expression wherever they appear.
+
+use tungsten_ast::{AssignOp, BinaryOp};
+use tungsten_lexer::Kind;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Precedence {
+    Lowest,
+    Assignment,
+    Range,
+    LogicalOr,
+    LogicalAnd,
+    Equality,
+    Comparison,
+    BitwiseOr,
+    BitwiseXor,
+    BitwiseAnd,
+    Shift,
+    Concat,
+    Additive,
+    Multiplicative,
+    Prefix,
+    Power,
+    Postfix,
+}
+
+impl Precedence {
+    /// The next tighter binding level
+    pub(crate) fn tighter(self) -> Self {
+        match self {
+            Precedence::Lowest => Precedence::Assignment,
+            Precedence::Assignment => Precedence::Range,
+            Precedence::Range => Precedence::LogicalOr,
+            Precedence::LogicalOr => Precedence::LogicalAnd,
+            Precedence::LogicalAnd => Precedence::Equality,
+            Precedence::Equality => Precedence::Comparison,
+            Precedence::Comparison => Precedence::BitwiseOr,
+            Precedence::BitwiseOr => Precedence::BitwiseXor,
+            Precedence::BitwiseXor => Precedence::BitwiseAnd,
+            Precedence::BitwiseAnd => Precedence::Shift,
+            Precedence::Shift => Precedence::Concat,
+            Precedence::Concat => Precedence::Additive,
+            Precedence::Additive => Precedence::Multiplicative,
+            Precedence::Multiplicative => Precedence::Prefix,
+            Precedence::Prefix => Precedence::Power,
+            Precedence::Power | Precedence::Postfix => Precedence::Postfix,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Associativity {
+    Left,
+    Right,
+    None,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum InfixOperator {
+    Binary(BinaryOp),
+    Assign(AssignOp),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct InfixEntry {
+    pub kind: Kind,
+    pub operator: InfixOperator,
+    pub precedence: Precedence,
+    pub associativity: Associativity,
+}
+
+const fn binary(
+    kind: Kind,
+    op: BinaryOp,
+    precedence: Precedence,
+    associativity: Associativity,
+) -> InfixEntry {
+    InfixEntry {
+        kind,
+        operator: InfixOperator::Binary(op),
+        precedence,
+        associativity,
+    }
+}
+
+const fn assign(kind: Kind, op: AssignOp) -> InfixEntry {
+    InfixEntry {
+        kind,
+        operator: InfixOperator::Assign(op),
+        precedence: Precedence::Assignment,
+        associativity: Associativity::Right,
+    }
+}
+
+pub(crate) const INFIX_OPERATORS: &[InfixEntry] = &[
+    assign(Kind::Equal, AssignOp::Assign),
+    assign(Kind::PlusAssign, AssignOp::Add),
+    assign(Kind::DashAssign, AssignOp::Sub),
+    assign(Kind::AsteriskAssign, AssignOp::Mul),
+    assign(Kind::SlashAssign, AssignOp::Div),
+    assign(Kind::DoubleSlashAssign, AssignOp::FloorDiv),
+    assign(Kind::PercentAssign, AssignOp::Rem),
+    assign(Kind::DoubleAsteriskAssign, AssignOp::Pow),
+    assign(Kind::DoubleLessAssign, AssignOp::Shl),
+    assign(Kind::DoubleGreaterAssign, AssignOp::Shr),
+    assign(Kind::AmpersandAssign, AssignOp::BitAnd),
+    assign(Kind::PipeAssign, AssignOp::BitOr),
+    assign(Kind::CaretAssign, AssignOp::BitXor),
+    binary(
+        Kind::DoublePeriod,
+        BinaryOp::Range,
+        Precedence::Range,
+        Associativity::None,
+    ),
+    binary(
+        Kind::DoublePeriodAssign,
+        BinaryOp::RangeInclusive,
+        Precedence::Range,
+        Associativity::None,
+    ),
+    binary(
+        Kind::DoublePipe,
+        BinaryOp::Or,
+        Precedence::LogicalOr,
+        Associativity::Left,
+    ),
+    binary(
+        Kind::DoubleAmpersand,
+        BinaryOp::And,
+        Precedence::LogicalAnd,
+        Associativity::Left,
+    ),
+    binary(
+        Kind::DoubleEqual,
+        BinaryOp::Eq,
+        Precedence::Equality,
+        Associativity::None,
+    ),
+    binary(
+        Kind::BangEqual,
+        BinaryOp::NotEq,
+        Precedence::Equality,
+        Associativity::None,
+    ),
+    binary(
+        Kind::Less,
+        BinaryOp::Less,
+        Precedence::Comparison,
+        Associativity::None,
+    ),
+    binary(
+        Kind::LessEq,
+        BinaryOp::LessEq,
+        Precedence::Comparison,
+        Associativity::None,
+    ),
+    binary(
+        Kind::Greater,
+        BinaryOp::Greater,
+        Precedence::Comparison,
+        Associativity::None,
+    ),
+    binary(
+        Kind::GreaterEq,
+        BinaryOp::GreaterEq,
+        Precedence::Comparison,
+        Associativity::None,
+    ),
+    binary(
+        Kind::Pipe,
+        BinaryOp::BitOr,
+        Precedence::BitwiseOr,
+        Associativity::Left,
+    ),
+    binary(
+        Kind::Caret,
+        BinaryOp::BitXor,
+        Precedence::BitwiseXor,
+        Associativity::Left,
+    ),
+    binary(
+        Kind::Ampersand,
+        BinaryOp::BitAnd,
+        Precedence::BitwiseAnd,
+        Associativity::Left,
+    ),
+    binary(
+        Kind::DoubleLess,
+        BinaryOp::Shl,
+        Precedence::Shift,
+        Associativity::Left,
+    ),
+    binary(
+        Kind::DoubleGreater,
+        BinaryOp::Shr,
+        Precedence::Shift,
+        Associativity::Left,
+    ),
+    binary(
+        Kind::LessGreater,
+        BinaryOp::Concat,
+        Precedence::Concat,
+        Associativity::Left,
+    ),
+    binary(
+        Kind::Plus,
+        BinaryOp::Add,
+        Precedence::Additive,
+        Associativity::Left,
+    ),
+    binary(
+        Kind::Dash,
+        BinaryOp::Sub,
+        Precedence::Additive,
+        Associativity::Left,
+    ),
+    binary(
+        Kind::Asterisk,
+        BinaryOp::Mul,
+        Precedence::Multiplicative,
+        Associativity::Left,
+    ),
+    binary(
+        Kind::Slash,
+        BinaryOp::Div,
+        Precedence::Multiplicative,
+        Associativity::Left,
+    ),
+    binary(
+        Kind::DoubleSlash,
+        BinaryOp::FloorDiv,
+        Precedence::Multiplicative,
+        Associativity::Left,
+    ),
+    binary(
+        Kind::Percent,
+        BinaryOp::Rem,
+        Precedence::Multiplicative,
+        Associativity::Left,
+    ),
+    binary(
+        Kind::DoubleAsterisk,
+        BinaryOp::Pow,
+        Precedence::Power,
+        Associativity::Right,
+    ),
+];
+
+pub(crate) fn infix_entry(kind: Kind) -> Option<&'static InfixEntry> {
+    INFIX_OPERATORS.iter().find(|entry| entry.kind == kind)
+}
@@ -0,0 +1,83 @@
+use tungsten_lexer::Kind;
+
+use crate::Parser;
+
+impl Parser<'_, '_, '_> {
+    /// Skips tokens until the start of the next top-level item
+    pub(crate) fn synchronize_item(&mut self) {
+        let mut depth = 0usize;
+
+        loop {
+            match self.peek_kind() {
+                Kind::Eof => return,
+                Kind::VarKw | Kind::ConstKw | Kind::DocComment if depth == 0 => return,
+                kind if depth == 0 && is_item_start(kind) => return,
+                Kind::LBrace => depth += 1,
+                Kind::RBrace => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+
+            self.advance();
+        }
+    }
+
+    /// Skips tokens until a statement boundary: past a `;` or a skipped nested block, or before a
+    /// `}` or the start of the next statement.
+    pub(crate) fn synchronize_statement(&mut self) {
+        let mut depth = 0usize;
+
+        loop {
+            match self.peek_kind() {
+                Kind::Eof => return,
+                Kind::Semicolon if depth == 0 => {
+                    self.advance();
+                    return;
+                }
+                Kind::RBrace if depth == 0 => return,
+                kind if depth == 0 && (is_statement_start(kind) || is_item_start(kind)) => return,
+                Kind::LBrace => depth += 1,
+                Kind::RBrace => {
+                    self.advance();
+
+                    // The end of a skipped block is a statement boundary as well
+                    depth -= 1;
+                    if depth == 0 {
+                        return;
+                    }
+
+                    continue;
+                }
+                _ => {}
+            }
+
+            self.advance();
+        }
+    }
+}
+
+/// Tokens that can only start an item, so a block reaching one is missing its `}`
+pub(crate) fn is_item_start(kind: Kind) -> bool {
+    matches!(
+        kind,
+        Kind::PubKw | Kind::FuncKw | Kind::ModuleKw | Kind::ImportKw
+    )
+}
+
+pub(crate) fn is_statement_start(kind: Kind) -> bool {
+    matches!(
+        kind,
+        Kind::VarKw
+            | Kind::ConstKw
+            | Kind::DocComment
+            | Kind::IfKw
+            | Kind::WhileKw
+            | Kind::ForKw
+            | Kind::LoopKw
+            | Kind::RepeatKw
+            | Kind::MatchKw
+            | Kind::DeferKw
+            | Kind::BreakKw
+            | Kind::ContinueKw
+            | Kind::ReturnKw
+    )
+}
@@ -0,0 +1,304 @@
+use tungsten_ast::{
+    Binding, Block, ElseBranch, ForStmt, IfStmt, MatchArm, MatchStmt, Mutability, Pattern,
+    PatternKind, RepeatStmt, Stmt, StmtKind, WhileStmt,
+};
+use tungsten_lexer::Kind;
+
+use crate::{errors::ParseError, parser::recovery::is_item_start, Parser};
+
+impl Parser<'_, '_, '_> {
+    pub(crate) fn parse_block(&mut self) -> Result<Block, ParseError> {
+        let start = self.expect(Kind::LBrace, "`{`")?.span.start;
+        let mut statements = Vec::new();
+
+        while !self.at(Kind::RBrace) && !self.at(Kind::Eof) && !is_item_start(self.peek_kind()) {
+            // Stray semicolons are empty statements
+            if self.eat(Kind::Semicolon) {
+                continue;
+            }
+
+            let doc_start = self.peek().span.start;
+            let doc = self.parse_doc_comments();
+
+            // Within a block, only bindings can be documented
+            if doc.is_some() && !matches!(self.peek_kind(), Kind::VarKw | Kind::ConstKw) {
+                self.report_unattached_doc_comment(doc_start);
+                continue;
+            }
+
+            let position = self.tokens.position();
+            let statement_start = self.peek().span.start;
+
+            match self.parse_statement() {
+                Ok(statement) => statements.push(Stmt { doc, ..statement }),
+                Err(err) => {
+                    self.report_error(err);
+                    self.synchronize_statement();
+
+                    // Always make progress, even if the statement failed on a synchronisation point
+                    if self.tokens.position() == position
+                        && !self.at(Kind::RBrace)
+                        && !self.at(Kind::Eof)
+                    {
+                        self.advance();
+                    }
+
+                    statements.push(Stmt {
+                        kind: StmtKind::Error,
+                        doc,
+                        span: self.span_from(statement_start),
+                    });
+                }
+            }
+        }
+
+        // A missing `}` is reported, but the block is kept so the enclosing item survives
+        if !self.eat(Kind::RBrace) {
+            let err = self.unexpected("`}`");
+            self.report_error(err);
+        }
+
+        Ok(Block {
+            statements,
+            span: self.span_from(start),
+        })
+    }
+
+    pub(crate) fn parse_statement(&mut self) -> Result<Stmt, ParseError> {
+        let start = self.peek().span.start;
+
+        let kind = match self.peek_kind() {
+            Kind::VarKw | Kind::ConstKw => {
+                let binding = self.parse_binding()?;
+                self.expect(Kind::Semicolon, "`;`")?;
+
+                StmtKind::Binding(binding)
+            }
+            Kind::LBrace => StmtKind::Block(self.parse_block()?),
+            Kind::IfKw => StmtKind::If(self.parse_if()?),
+            Kind::WhileKw => {
+                self.advance();
+                let condition = self.parse_expression()?;
+                let body = self.parse_block()?;
+
+                StmtKind::While(WhileStmt { condition, body })
+            }
+            Kind::ForKw => {
+                self.advance();
+                let binding = self.expect_identifier()?;
+                self.expect(Kind::InKw, "`in`")?;
+                let iterable = self.parse_expression()?;
+                let body = self.parse_block()?;
+
+                StmtKind::For(ForStmt {
+                    binding,
+                    iterable,
+                    body,
+                })
+            }
+            Kind::LoopKw => {
+                self.advance();
+
+                StmtKind::Loop(self.parse_block()?)
+            }
+            Kind::RepeatKw => {
+                self.advance();
+                let body = self.parse_block()?;
+                self.expect(Kind::UntilKw, "`until`")?;
+                let condition = self.parse_expression()?;
+                self.expect(Kind::Semicolon, "`;`")?;
+
+                StmtKind::Repeat(RepeatStmt { body, condition })
+            }
+            Kind::MatchKw => StmtKind::Match(self.parse_match()?),
+            Kind::DeferKw => {
+                self.advance();
+
+                StmtKind::Defer(Box::new(self.parse_statement()?))
+            }
+            Kind::BreakKw => {
+                self.advance();
+                self.expect(Kind::Semicolon, "`;`")?;
+
+                StmtKind::Break
+            }
+            Kind::ContinueKw => {
+                self.advance();
+                self.expect(Kind::Semicolon, "`;`")?;
+
+                StmtKind::Continue
+            }
+            Kind::ReturnKw => {
+                self.advance();
+
+                let value = if self.at(Kind::Semicolon) {
+                    None
+                } else {
+                    Some(self.parse_expression()?)
+                };
+                self.expect(Kind::Semicolon, "`;`")?;
+
+                StmtKind::Return(value)
+            }
+            _ => {
+                let expr = self.parse_expression()?;
+                self.expect(Kind::Semicolon, "`;`")?;
+
+                StmtKind::Expr(expr)
+            }
+        };
+
+        Ok(Stmt {
+            kind,
+            doc: None,
+            span: self.span_from(start),
+        })
+    }
+
+    /// `var` or `const` binding, without the trailing `;`
+    pub(crate) fn parse_binding(&mut self) -> Result<Binding, ParseError> {
+        let mutability = match self.advance().kind {
+            Kind::ConstKw => Mutability::Const,
+            _ => Mutability::Var,
+        };
+
+        let name = self.expect_identifier()?;
+
+        let ty = if self.eat(Kind::Colon) {
+            Some(self.parse_type()?)
+        } else {
+            None
+        };
+
+        let value = if self.eat(Kind::Equal) {
+            Some(self.parse_expression()?)
+        } else {
+            None
+        };
+
+        Ok(Binding {
+            mutability,
+            name,
+            ty,
+            value,
+        })
+    }
+
+    fn parse_if(&mut self) -> Result<IfStmt, ParseError> {
+        self.expect(Kind::IfKw, "`if`")?;
+        let condition = self.parse_expression()?;
+        let then_branch = self.parse_block()?;
+
+        let else_branch = if self.eat(Kind::ElseKw) {
+            let branch = match self.peek_kind() {
+                Kind::IfKw => ElseBranch::If(self.parse_if()?),
+                _ => ElseBranch::Block(self.parse_block()?),
+            };
+
+            Some(Box::new(branch))
+        } else {
+            None
+        };
+
+        Ok(IfStmt {
+            condition,
+            then_branch,
+            else_branch,
+        })
+    }
+
+    fn parse_match(&mut self) -> Result<MatchStmt, ParseError> {
+        self.expect(Kind::MatchKw, "`match`")?;
+        let scrutinee = self.parse_expression()?;
+        self.expect(Kind::LBrace, "`{`")?;
+
+        let mut arms = Vec::new();
+        while !self.at(Kind::RBrace) && !self.at(Kind::Eof) {
+            let start = self.peek().span.start;
+            let pattern = self.parse_pattern()?;
+            self.expect(Kind::FatArrow, "`=>`")?;
+
+            let body = if self.at(Kind::LBrace) {
+                let block = self.parse_block()?;
+                let span = block.span;
+
+                Stmt {
+                    kind: StmtKind::Block(block),
+                    doc: None,
+                    span,
+                }
+            } else {
+                let expr = self.parse_expression()?;
+                let span = expr.span;
+
+                Stmt {
+                    kind: StmtKind::Expr(expr),
+                    doc: None,
+                    span,
+                }
+            };
+
+            arms.push(MatchArm {
+                pattern,
+                body: Box::new(body),
+                span: self.span_from(start),
+            });
+
+            if !self.eat(Kind::Comma) && !self.at(Kind::RBrace) {
+                return Err(self.unexpected("`,` or `}`"));
+            }
+        }
+
+        self.expect(Kind::RBrace, "`}`")?;
+
+        Ok(MatchStmt { scrutinee, arms })
+    }
+
+    fn parse_pattern(&mut self) -> Result<Pattern, ParseError> {
+        let start = self.peek().span.start;
+
+        let kind = if self.at(Kind::Identifier) {
+            let ident = self.expect_identifier()?;
+
+            if ident.name.as_ref() == "_" {
+                PatternKind::Wildcard
+            } else {
+                PatternKind::Binding(ident)
+            }
+        } else {
+            let literal = match self.parse_literal() {
+                Some(literal) => literal,
+                None => return Err(self.unexpected("pattern")),
+            };
+
+            let inclusive = match self.peek_kind() {
+                Kind::DoublePeriod => Some(false),
+                Kind::DoublePeriodAssign => Some(true),
+                _ => None,
+            };
+
+            match inclusive {
+                Some(inclusive) => {
+                    self.advance();
+
+                    let end = match self.parse_literal() {
+                        Some(literal) => literal,
+                        None => return Err(self.unexpected("literal")),
+                    };
+
+                    PatternKind::Range {
+                        start: literal,
+                        end,
+                        inclusive,
+                    }
+                }
+                None => PatternKind::Literal(literal),
+            }
+        };
+
+        Ok(Pattern {
+            kind,
+            span: self.span_from(start),
+        })
+    }
+}
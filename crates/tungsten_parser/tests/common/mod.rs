@@ -0,0 +1,36 @@
+//! Helpers shared by the parser tests.
+
+use std::path::Path;
+
+use tungsten_ast::Program;
+use tungsten_context::CompilerContext;
+use tungsten_lexer::Lexer;
+use tungsten_parser::Parser;
+
+/// Parses `source`, returning the program and the code and labelled source of each diagnostic
+pub fn parse(source: &str) -> (Program, Vec<String>) {
+    let mut ctx = CompilerContext::new(Path::new("test.tung"), source, Path::new("target"));
+
+    let file = ctx.main_file();
+    let tokens = Lexer::new(&mut ctx, file, source).tokenize();
+    let program = Parser::new(&mut ctx, tokens).parse();
+
+    let diagnostics = ctx
+        .errors()
+        .iter()
+        .map(|diagnostic| {
+            let labels: Vec<&str> = diagnostic
+                .labels
+                .iter()
+                .map(|label| &source[label.range.clone()])
+                .collect();
+
+            format!(
+                "{} {labels:?}",
+                diagnostic.code.as_deref().unwrap_or_default()
+            )
+        })
+        .collect();
+
+    (program, diagnostics)
+}
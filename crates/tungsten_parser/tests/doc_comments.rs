@@ -0,0 +1,99 @@
+//! Attachment of `##` doc comments to items and local bindings.
+
+mod common;
+
+use common::parse;
+use tungsten_ast::{ItemKind, Program, StmtKind};
+use tungsten_utils::{atom, Atom};
+
+/// Docs of the statements of the body of the function `program` ends with
+fn statement_docs(program: &Program) -> Vec<Option<Atom>> {
+    let Some(ItemKind::Function(function)) = program.items.last().map(|item| &item.kind) else {
+        panic!("expected function");
+    };
+
+    function
+        .body
+        .statements
+        .iter()
+        .map(|statement| statement.doc.clone())
+        .collect()
+}
+
+#[test]
+fn items_are_documented() {
+    let (program, diagnostics) = parse(
+        "## Entry point\n## of the program\npub func main() {}\n\
+         ## Limit\nconst limit = 1;\nfunc other() {}\n",
+    );
+    assert_eq!(diagnostics, Vec::<String>::new());
+
+    let docs: Vec<_> = program.items.iter().map(|item| item.doc.clone()).collect();
+    assert_eq!(
+        docs,
+        vec![
+            Some(atom!("Entry point\nof the program")),
+            Some(atom!("Limit")),
+            None,
+        ]
+    );
+}
+
+#[test]
+fn local_bindings_are_documented() {
+    let (program, diagnostics) = parse(
+        "func main() {\n    ## Counter\n    var count = 0;\n    ## Step\n    ## size\n    \
+         const step = 2;\n    count += step;\n}",
+    );
+    assert_eq!(diagnostics, Vec::<String>::new());
+
+    assert_eq!(
+        statement_docs(&program),
+        vec![Some(atom!("Counter")), Some(atom!("Step\nsize")), None]
+    );
+}
+
+#[test]
+fn docs_before_other_statements_are_unattached() {
+    let (program, diagnostics) = parse("func main() {\n    ## Nothing\n    x = 1;\n    ## End\n}");
+
+    assert_eq!(
+        diagnostics,
+        vec!["W001 [\"## Nothing\"]", "W001 [\"## End\"]"]
+    );
+    // The statement itself is still parsed
+    assert_eq!(statement_docs(&program), vec![None]);
+
+    let ItemKind::Function(function) = &program.items[0].kind else {
+        panic!("expected function");
+    };
+    assert!(matches!(
+        function.body.statements[0].kind,
+        StmtKind::Expr(_)
+    ));
+}
+
+#[test]
+fn orphan_top_level_docs_are_unattached() {
+    let cases = [
+        (
+            "func main() {}\n## Trailing",
+            vec!["W001 [\"## Trailing\"]"],
+        ),
+        ("## Only\n## docs", vec!["W001 [\"## Only\\n## docs\"]"]),
+        (
+            "## Stray\n;\nfunc main() {}",
+            vec!["W001 [\"## Stray\"]", "E007 [\";\"]"],
+        ),
+    ];
+
+    for (source, expected) in cases {
+        let (program, diagnostics) = parse(source);
+
+        assert_eq!(diagnostics, expected, "parsing `{source}`");
+        assert!(
+            program.items.iter().all(|item| item.doc.is_none()),
+            "parsing `{source}`"
+        );
+    }
+}
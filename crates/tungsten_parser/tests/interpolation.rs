@@ -0,0 +1,66 @@
+//! Desugaring of interpolated string literals into concatenations.
+
+mod common;
+
+use tungsten_ast::{Expr, ExprKind, ItemKind, Literal, StmtKind};
+
+/// Parses `source` as an expression statement, rendering it as an s-expression along with the
+/// source its span covers
+fn parse(source: &str) -> (String, String) {
+    let source = format!("func test() {{ {source}; }}");
+    let (program, diagnostics) = common::parse(&source);
+    assert_eq!(diagnostics, Vec::<String>::new(), "parsing `{source}`");
+
+    let ItemKind::Function(function) = &program.items[0].kind else {
+        panic!("expected function");
+    };
+    let StmtKind::Expr(expr) = &function.body.statements[0].kind else {
+        panic!("expected expression statement");
+    };
+
+    (render(expr), source[expr.span.range()].to_string())
+}
+
+fn render(expr: &Expr) -> String {
+    match &expr.kind {
+        ExprKind::Literal(Literal::String(text)) => format!("{:?}", text.as_ref()),
+        ExprKind::Literal(Literal::Integer(value, _)) => value.to_string(),
+        ExprKind::Literal(literal) => format!("{literal:?}"),
+        ExprKind::Identifier(ident) => ident.name.to_string(),
+        ExprKind::Binary { op, lhs, rhs } => {
+            format!("({} {} {})", op.as_str(), render(lhs), render(rhs))
+        }
+        ExprKind::Call { callee, args } => {
+            let args: Vec<_> = args.iter().map(render).collect();
+            format!("(call {} {})", render(callee), args.join(" "))
+        }
+        other => panic!("unexpected expression {other:?}"),
+    }
+}
+
+#[test]
+fn interpolations_are_concatenations() {
+    let cases = [
+        (r#""a {x} b""#, r#"(<> (<> "a " x) " b")"#),
+        (r#""{x}""#, r#"(<> "" x)"#),
+        (r#""{x}{y}""#, r#"(<> (<> "" x) y)"#),
+        (r#""a{x}b{y}c""#, r#"(<> (<> (<> (<> "a" x) "b") y) "c")"#),
+        (r#""{x + 1}""#, r#"(<> "" (+ x 1))"#),
+        (r#""{f(x)}!""#, r#"(<> (<> "" (call f x)) "!")"#),
+        (r#""a{ "b{c}" }""#, r#"(<> "a" (<> "b" c))"#),
+        (r#""\{{x}\}""#, r#"(<> (<> "{" x) "}")"#),
+    ];
+
+    for (source, expected) in cases {
+        let (rendered, span) = parse(source);
+
+        assert_eq!(rendered, expected, "parsing `{source}`");
+        assert_eq!(span, source, "span of `{source}`");
+    }
+}
+
+#[test]
+fn interpolated_strings_are_operands() {
+    assert_eq!(parse(r#""{x}" <> y"#).0, r#"(<> (<> "" x) y)"#);
+    assert_eq!(parse(r#"f("{x}")"#).0, r#"(call f (<> "" x))"#);
+}
@@ -0,0 +1,49 @@
+//! Literals whose validity depends on the surrounding expression.
+
+mod common;
+
+/// Parses `source` as an expression statement, returning the code and labelled source of each
+/// diagnostic
+fn diagnostics(source: &str) -> Vec<String> {
+    common::parse(&format!("func test() {{ {source}; }}")).1
+}
+
+#[test]
+fn signed_minimums_are_only_valid_negated() {
+    for source in [
+        "-128i8",
+        "-32768i16",
+        "-2147483648i32",
+        "-9223372036854775808i64",
+        "x = -128i8",
+        "-128i8 + 1",
+    ] {
+        assert_eq!(
+            diagnostics(source),
+            Vec::<String>::new(),
+            "parsing `{source}`"
+        );
+    }
+
+    for (source, label) in [
+        ("128i8", "128i8"),
+        ("x = 128i8", "128i8"),
+        ("1 - 128i8", "128i8"),
+        ("-(128i8)", "128i8"),
+        ("-128i8 ** 2", "128i8"),
+        ("-128i8.bits", "128i8"),
+        ("-9223372036854775808i64()", "9223372036854775808i64"),
+    ] {
+        assert_eq!(
+            diagnostics(source),
+            vec![format!("E019 [{label:?}]")],
+            "parsing `{source}`"
+        );
+    }
+}
+
+#[test]
+fn unsigned_and_larger_literals_are_reported_once() {
+    assert_eq!(diagnostics("-256u8"), vec!["E019 [\"256u8\"]"]);
+    assert_eq!(diagnostics("-129i8"), vec!["E019 [\"129i8\"]"]);
+}
@@ -0,0 +1,151 @@
+//! Precedence and associativity of every operator pair exercised by `symbols.tung`.
+
+mod common;
+
+use tungsten_ast::{Expr, ExprKind, ItemKind, Literal, StmtKind};
+
+/// Parses `source` as an expression statement, rendering it as an s-expression
+fn parse(source: &str) -> Option<String> {
+    let (program, diagnostics) = common::parse(&format!("func test() {{ {source}; }}"));
+    if !diagnostics.is_empty() {
+        return None;
+    }
+
+    let ItemKind::Function(function) = &program.items[0].kind else {
+        panic!("expected function");
+    };
+    let StmtKind::Expr(expr) = &function.body.statements[0].kind else {
+        panic!("expected expression statement");
+    };
+
+    Some(render(expr))
+}
+
+fn render(expr: &Expr) -> String {
+    match &expr.kind {
+        ExprKind::Literal(Literal::Integer(value, _)) => value.to_string(),
+        ExprKind::Literal(literal) => format!("{literal:?}"),
+        ExprKind::Identifier(ident) => ident.name.to_string(),
+        ExprKind::Unary { op, operand } if op.is_postfix() => {
+            format!("(post{} {})", op.as_str(), render(operand))
+        }
+        ExprKind::Unary { op, operand } => format!("({} {})", op.as_str(), render(operand)),
+        ExprKind::Binary { op, lhs, rhs } => {
+            format!("({} {} {})", op.as_str(), render(lhs), render(rhs))
+        }
+        ExprKind::Assign { op, target, value } => {
+            format!("({} {} {})", op.as_str(), render(target), render(value))
+        }
+        ExprKind::Call { callee, args } => {
+            let args: Vec<_> = args.iter().map(render).collect();
+            format!("(call {} {})", render(callee), args.join(" "))
+        }
+        ExprKind::Index { target, index } => {
+            format!("(index {} {})", render(target), render(index))
+        }
+        ExprKind::Member { target, member } => format!("(. {} {})", render(target), member.name),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Assoc {
+    Left,
+    Right,
+    None,
+}
+
+/// Binary operators from `symbols.tung` with their expected binding level and associativity
+const BINARY: &[(&str, u8, Assoc)] = &[
+    ("^=", 1, Assoc::Right),
+    ("+=", 1, Assoc::Right),
+    (">>=", 1, Assoc::Right),
+    ("..", 2, Assoc::None),
+    ("..=", 2, Assoc::None),
+    ("==", 5, Assoc::None),
+    ("!=", 5, Assoc::None),
+    ("<", 6, Assoc::None),
+    (">", 6, Assoc::None),
+    ("^", 8, Assoc::Left),
+    ("<>", 11, Assoc::Left),
+];
+
+#[test]
+fn binary_operator_pairs() {
+    for &(first, first_level, first_assoc) in BINARY {
+        for &(second, second_level, second_assoc) in BINARY {
+            let source = format!("a {first} b {second} c");
+            let left = format!("({second} ({first} a b) c)");
+            let right = format!("({first} a ({second} b c))");
+
+            let expected = if second_level == 1 && first_level != 1 {
+                // `a op b` is not a valid assignment target
+                None
+            } else if first_level > second_level {
+                Some(left)
+            } else if first_level < second_level {
+                Some(right)
+            } else {
+                match (first_assoc, second_assoc) {
+                    (Assoc::Left, Assoc::Left) => Some(left),
+                    (Assoc::Right, Assoc::Right) => Some(right),
+                    _ => None,
+                }
+            };
+
+            assert_eq!(parse(&source), expected, "parsing `{source}`");
+        }
+    }
+}
+
+#[test]
+fn prefix_and_postfix_operators() {
+    let cases = [
+        ("~a ^ b", "(^ (~ a) b)"),
+        ("a ^ ~b", "(^ a (~ b))"),
+        ("~a < b", "(< (~ a) b)"),
+        ("++a <> b", "(<> (++ a) b)"),
+        ("a++ <> b", "(<> (post++ a) b)"),
+        ("--a == b", "(== (-- a) b)"),
+        ("a-- != b", "(!= (post-- a) b)"),
+        ("a++ .. b--", "(.. (post++ a) (post-- b))"),
+        ("++a.b", "(++ (. a b))"),
+        ("a.b++", "(post++ (. a b))"),
+        ("~a.b", "(~ (. a b))"),
+        ("a += b++", "(+= a (post++ b))"),
+        ("a ^= ~b", "(^= a (~ b))"),
+        ("a.b >>= c", "(>>= (. a b) c)"),
+        ("-a ** b", "(- (** a b))"),
+        ("a ** -b", "(** a (- b))"),
+        ("a ** b ** c", "(** a (** b c))"),
+        ("a // b * c", "(* (// a b) c)"),
+        ("a << b <> c", "(<< a (<> b c))"),
+        ("a | b ^ c & d", "(| a (^ b (& c d)))"),
+        ("a && b || c", "(|| (&& a b) c)"),
+        ("a .. b + 1", "(.. a (+ b 1))"),
+        ("f(a)[b].c", "(. (index (call f a) b) c)"),
+    ];
+
+    for (source, expected) in cases {
+        assert_eq!(
+            parse(source).as_deref(),
+            Some(expected),
+            "parsing `{source}`"
+        );
+    }
+}
+
+#[test]
+fn invalid_operator_combinations() {
+    let cases = [
+        "1++",
+        "++(a + b)",
+        "a + b = c",
+        "a < b < c",
+        "a .. b ..= c",
+        "-a = b",
+    ];
+
+    for source in cases {
+        assert_eq!(parse(source), None, "parsing `{source}`");
+    }
+}
@@ -0,0 +1,151 @@
+//! Recovery from syntax errors, which are replaced by error nodes while their valid siblings are
+//! kept.
+
+mod common;
+
+use tungsten_ast::{ItemKind, StmtKind};
+
+/// Parses `source`, returning its items and the code and labelled source of each diagnostic.
+/// Functions are rendered as the list of their statements, error nodes as `!` followed by the
+/// source they cover.
+fn parse(source: &str) -> (Vec<String>, Vec<String>) {
+    let (program, diagnostics) = common::parse(source);
+
+    let items = program
+        .items
+        .iter()
+        .map(|item| match &item.kind {
+            ItemKind::Function(function) => {
+                let statements: Vec<_> = function
+                    .body
+                    .statements
+                    .iter()
+                    .map(|statement| {
+                        let text = &source[statement.span.range()];
+                        match statement.kind {
+                            StmtKind::Error => format!("!{text}"),
+                            _ => text.to_string(),
+                        }
+                    })
+                    .collect();
+
+                format!("[{}]", statements.join(" "))
+            }
+            ItemKind::Error => format!("!{}", &source[item.span.range()]),
+            _ => source[item.span.range()].to_string(),
+        })
+        .collect();
+
+    (items, diagnostics)
+}
+
+#[test]
+fn every_error_of_a_file_is_reported() {
+    let (items, diagnostics) = parse(
+        "func a() { x = ; y = 1; f(; }\n\
+         func b() { if { x; } z; }\n\
+         5;\n\
+         const c = 1;",
+    );
+
+    assert_eq!(
+        items,
+        vec![
+            "[!x = ; y = 1; !f(;]",
+            "[!if { x; } z;]",
+            "!5;",
+            "const c = 1;"
+        ]
+    );
+    assert_eq!(
+        diagnostics,
+        vec![
+            "E008 [\";\"]",
+            "E008 [\";\"]",
+            "E008 [\"{\"]",
+            "E007 [\"5\"]"
+        ]
+    );
+}
+
+#[test]
+fn statements_synchronize_past_semicolons() {
+    let (items, diagnostics) = parse("func a() { x = ) ; y; x = 1 y = 2; z; }");
+
+    assert_eq!(items, vec!["[!x = ) ; y; !x = 1 y = 2; z;]"]);
+    assert_eq!(diagnostics, vec!["E008 [\")\"]", "E007 [\"y\"]"]);
+}
+
+#[test]
+fn statements_synchronize_at_closing_braces() {
+    // The `}` ending the function is not skipped along with the statement
+    let (items, diagnostics) = parse("func a() { x = }\nfunc b() { y; }");
+
+    assert_eq!(items, vec!["[!x =]", "[y;]"]);
+    assert_eq!(diagnostics, vec!["E008 [\"}\"]"]);
+
+    // Nor is a nested block cut in the middle
+    let (items, _) = parse("func a() { x = (1 { 2; } z; }");
+    assert_eq!(items, vec!["[!x = (1 { 2; } z;]"]);
+}
+
+#[test]
+fn statements_synchronize_at_the_next_statement() {
+    let (items, diagnostics) = parse("func a() { x = var k = 1; f( const c = 2; g( if a {} }");
+
+    assert_eq!(
+        items,
+        vec!["[!x = var k = 1; !f( const c = 2; !g( if a {}]"]
+    );
+    assert_eq!(
+        diagnostics,
+        vec!["E008 [\"var\"]", "E008 [\"const\"]", "E008 [\"if\"]"]
+    );
+}
+
+#[test]
+fn blocks_cut_off_by_an_item_end_before_it() {
+    for keyword in ["func", "pub", "module", "import"] {
+        let source = format!("func a() {{ x = 1;\n{keyword} b");
+        let (items, diagnostics) = parse(&source);
+
+        assert_eq!(items[0], "[x = 1;]", "parsing `{source}`");
+        assert_eq!(
+            diagnostics[0],
+            format!("E007 [\"{keyword}\"]"),
+            "parsing `{source}`"
+        );
+    }
+}
+
+#[test]
+fn items_synchronize_at_the_next_item() {
+    let (items, diagnostics) =
+        parse("5; func b() {} var = ; const c = 1; pub 3; module m; } ) var d; + import x;");
+
+    assert_eq!(
+        items,
+        vec![
+            "!5;",
+            "[]",
+            "!var = ;",
+            "const c = 1;",
+            "!pub 3;",
+            "module m;",
+            "!} )",
+            "var d;",
+            "!+",
+            "import x;",
+        ]
+    );
+    assert_eq!(
+        diagnostics,
+        vec![
+            "E007 [\"5\"]",
+            "E007 [\"=\"]",
+            "E007 [\"3\"]",
+            "E007 [\"}\"]",
+            "E007 [\"+\"]"
+        ]
+    );
+}
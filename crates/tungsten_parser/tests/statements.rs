@@ -0,0 +1,224 @@
+//! Parsing of control flow and other statements.
+
+mod common;
+
+use tungsten_ast::{
+    Block, ElseBranch, Expr, ExprKind, IfStmt, ItemKind, Literal, Mutability, PatternKind, Stmt,
+    StmtKind,
+};
+
+/// Parses `source` as the body of a function, rendering each statement as an s-expression
+fn parse(source: &str) -> Vec<String> {
+    let source = format!("func test() {{ {source} }}");
+    let (program, diagnostics) = common::parse(&source);
+    assert_eq!(diagnostics, Vec::<String>::new(), "parsing `{source}`");
+
+    let ItemKind::Function(function) = &program.items[0].kind else {
+        panic!("expected function");
+    };
+
+    function.body.statements.iter().map(render).collect()
+}
+
+fn render(statement: &Stmt) -> String {
+    match &statement.kind {
+        StmtKind::Binding(binding) => {
+            let keyword = match binding.mutability {
+                Mutability::Const => "const",
+                Mutability::Var => "var",
+            };
+            let value = binding.value.as_ref().map(render_expr);
+
+            format!(
+                "({keyword} {} {})",
+                binding.name.name,
+                value.unwrap_or_default()
+            )
+        }
+        StmtKind::Expr(expr) => render_expr(expr),
+        StmtKind::Block(block) => render_block(block),
+        StmtKind::If(if_stmt) => render_if(if_stmt),
+        StmtKind::While(while_stmt) => format!(
+            "(while {} {})",
+            render_expr(&while_stmt.condition),
+            render_block(&while_stmt.body)
+        ),
+        StmtKind::For(for_stmt) => format!(
+            "(for {} {} {})",
+            for_stmt.binding.name,
+            render_expr(&for_stmt.iterable),
+            render_block(&for_stmt.body)
+        ),
+        StmtKind::Loop(body) => format!("(loop {})", render_block(body)),
+        StmtKind::Repeat(repeat) => format!(
+            "(repeat {} {})",
+            render_block(&repeat.body),
+            render_expr(&repeat.condition)
+        ),
+        StmtKind::Match(match_stmt) => {
+            let arms: Vec<_> = match_stmt
+                .arms
+                .iter()
+                .map(|arm| {
+                    let pattern = match &arm.pattern.kind {
+                        PatternKind::Wildcard => "_".to_string(),
+                        PatternKind::Literal(literal) => render_literal(literal),
+                        PatternKind::Binding(ident) => ident.name.to_string(),
+                        PatternKind::Range {
+                            start,
+                            end,
+                            inclusive,
+                        } => {
+                            let op = if *inclusive { "..=" } else { ".." };
+                            format!("{}{op}{}", render_literal(start), render_literal(end))
+                        }
+                    };
+
+                    format!("({pattern} {})", render(&arm.body))
+                })
+                .collect();
+
+            format!(
+                "(match {} {})",
+                render_expr(&match_stmt.scrutinee),
+                arms.join(" ")
+            )
+        }
+        StmtKind::Defer(deferred) => format!("(defer {})", render(deferred)),
+        StmtKind::Break => "break".to_string(),
+        StmtKind::Continue => "continue".to_string(),
+        StmtKind::Return(None) => "(|>)".to_string(),
+        StmtKind::Return(Some(value)) => format!("(|> {})", render_expr(value)),
+        StmtKind::Error => "error".to_string(),
+    }
+}
+
+fn render_block(block: &Block) -> String {
+    let statements: Vec<_> = block.statements.iter().map(render).collect();
+    format!("{{{}}}", statements.join(" "))
+}
+
+fn render_if(if_stmt: &IfStmt) -> String {
+    let condition = render_expr(&if_stmt.condition);
+    let then_branch = render_block(&if_stmt.then_branch);
+
+    match if_stmt.else_branch.as_deref() {
+        None => format!("(if {condition} {then_branch})"),
+        Some(ElseBranch::If(else_if)) => {
+            format!("(if {condition} {then_branch} {})", render_if(else_if))
+        }
+        Some(ElseBranch::Block(block)) => {
+            format!("(if {condition} {then_branch} {})", render_block(block))
+        }
+    }
+}
+
+fn render_literal(literal: &Literal) -> String {
+    match literal {
+        Literal::Integer(value, ..) => value.to_string(),
+        Literal::String(text) => format!("{:?}", text.as_ref()),
+        other => format!("{other:?}"),
+    }
+}
+
+fn render_expr(expr: &Expr) -> String {
+    match &expr.kind {
+        ExprKind::Literal(literal) => render_literal(literal),
+        ExprKind::Identifier(ident) => ident.name.to_string(),
+        ExprKind::Unary { op, operand } => format!("({} {})", op.as_str(), render_expr(operand)),
+        ExprKind::Binary { op, lhs, rhs } => {
+            format!(
+                "({} {} {})",
+                op.as_str(),
+                render_expr(lhs),
+                render_expr(rhs)
+            )
+        }
+        ExprKind::Assign { op, target, value } => {
+            format!(
+                "({} {} {})",
+                op.as_str(),
+                render_expr(target),
+                render_expr(value)
+            )
+        }
+        ExprKind::Call { callee, args } => {
+            let args: Vec<_> = args.iter().map(render_expr).collect();
+            format!("(call {} {})", render_expr(callee), args.join(" "))
+        }
+        other => panic!("unexpected expression {other:?}"),
+    }
+}
+
+#[test]
+fn bindings_and_expressions() {
+    assert_eq!(
+        parse("var a = 1; const b: u8 = a + 2; var c; f(a); ;; { a = b; }"),
+        vec![
+            "(var a 1)",
+            "(const b (+ a 2))",
+            "(var c )",
+            "(call f a)",
+            "{(= a b)}",
+        ]
+    );
+}
+
+#[test]
+fn if_else() {
+    assert_eq!(
+        parse("if a { b; } if a { b; } else { c; } if a {} else if b { c; } else {}"),
+        vec!["(if a {b})", "(if a {b} {c})", "(if a {} (if b {c} {}))"]
+    );
+}
+
+#[test]
+fn loops() {
+    assert_eq!(
+        parse(
+            "while a < 10 { a += 1; } for i in 0..10 { f(i); } loop { break; } \
+             repeat { a -= 1; } until a == 0;"
+        ),
+        vec![
+            "(while (< a 10) {(+= a 1)})",
+            "(for i (.. 0 10) {(call f i)})",
+            "(loop {break})",
+            "(repeat {(-= a 1)} (== a 0))",
+        ]
+    );
+}
+
+#[test]
+fn break_and_continue() {
+    assert_eq!(
+        parse("loop { if a { continue; } break; }"),
+        vec!["(loop {(if a {continue}) break})"]
+    );
+}
+
+#[test]
+fn match_arms() {
+    assert_eq!(
+        parse(r#"match a { 0 => b, 1..5 => { c; }, 5..=9 => d(a), "x" => e, n => n, _ => f }"#),
+        vec![r#"(match a (0 b) (1..5 {c}) (5..=9 (call d a)) ("x" e) (n n) (_ f))"#]
+    );
+
+    // The last arm may end with a comma, or the match be empty
+    assert_eq!(
+        parse("match a { _ => b, } match a {}"),
+        vec!["(match a (_ b))", "(match a )"]
+    );
+}
+
+#[test]
+fn defer() {
+    assert_eq!(
+        parse("defer close(a); defer { a = 0; }"),
+        vec!["(defer (call close a))", "(defer {(= a 0)})"]
+    );
+}
+
+#[test]
+fn pipe_returns() {
+    assert_eq!(parse("|> a + 1; |>;"), vec!["(|> (+ a 1))", "(|>)"]);
+}
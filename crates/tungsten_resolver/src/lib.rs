@@ -0,0 +1,5 @@
+pub use resolutions::*;
+pub use resolver::*;
+
+mod resolutions;
+mod resolver;
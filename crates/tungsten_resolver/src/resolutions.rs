@@ -0,0 +1,25 @@
+use std::collections::HashMap;
+
+use tungsten_context::Span;
+use tungsten_modules::ModuleId;
+use tungsten_symbols::{ScopeId, SymbolId};
+
+/// Symbols the identifiers of a compilation refer to, as found by the [`Resolver`](crate::Resolver)
+#[derive(Debug, Clone, Default)]
+pub struct Resolutions {
+    /// Symbol of each identifier use, by span of the identifier
+    pub(crate) uses: HashMap<Span, SymbolId>,
+    pub(crate) module_scopes: HashMap<ModuleId, ScopeId>,
+}
+
+impl Resolutions {
+    /// Returns the symbol the identifier at `span` refers to, unless it is undeclared
+    pub fn resolved(&self, span: Span) -> Option<&SymbolId> {
+        self.uses.get(&span)
+    }
+
+    /// Returns the scope holding the items of `module`
+    pub fn module_scope(&self, module: ModuleId) -> Option<ScopeId> {
+        self.module_scopes.get(&module).copied()
+    }
+}
@@ -0,0 +1,487 @@
+use tungsten_ast::{
+    Binding, Block, ElseBranch, Expr, ExprKind, Function, Ident, IfStmt, Item, ItemKind, Literal,
+    Mutability, PatternKind, Stmt, StmtKind, Type, TypeKind, UnaryOp, Visibility,
+};
+use tungsten_context::{error_builders, CompilerContext};
+use tungsten_modules::{ModuleId, ModuleTree};
+use tungsten_symbols::{
+    ConstantValue, ScopeId, ScopeKind, Symbol, SymbolAttribute, SymbolAttributeValue, SymbolFlags,
+    SymbolId, TypeRef,
+};
+use tungsten_utils::{edit_distance, Atom};
+
+use crate::Resolutions;
+
+/// Binds every identifier of a module tree to the symbol it refers to, declaring items, bindings
+/// [`attributes`](tungsten_symbols::SymbolAttribute).
+/// [`attributes`](tungsten_symbols::attributes).
+///
+/// Items are visible from the whole module, and public items of imported modules from the
+/// modules importing them. Local bindings are only visible after their declaration.
+#[derive(Debug)]
+pub struct Resolver<'a, 'ctx> {
+    context: &'a mut CompilerContext<'ctx>,
+    modules: &'a ModuleTree,
+    resolutions: Resolutions,
+    /// Module whose items are being resolved
+    module: ModuleId,
+}
+
+impl<'a, 'ctx> Resolver<'a, 'ctx> {
+    pub fn new(context: &'a mut CompilerContext<'ctx>, modules: &'a ModuleTree) -> Self {
+        Self {
+            context,
+            modules,
+            resolutions: Resolutions::default(),
+            module: modules.root(),
+        }
+    }
+
+    /// Declares the items of every module, then resolves their contents. Undeclared and
+    /// duplicate names, as well as shadowing if enabled, are reported to the context.
+    pub fn resolve(mut self) -> Resolutions {
+        let modules = self.modules;
+
+        for (id, module) in modules.iter() {
+            let scope = self.context.scopes.push_scope(ScopeKind::Module);
+            self.resolutions.module_scopes.insert(id, scope);
+            self.module = id;
+
+            for item in module.program.iter().flat_map(|program| &program.items) {
+                self.declare_item(item);
+            }
+
+            self.context.scopes.pop_scope();
+        }
+
+        for (id, module) in modules.iter() {
+            let Some(program) = &module.program else {
+                continue;
+            };
+
+            self.module = id;
+            self.context
+                .scopes
+                .enter(self.resolutions.module_scopes[&id]);
+
+            for item in &program.items {
+                self.resolve_item(item);
+            }
+
+            self.context.scopes.pop_scope();
+        }
+
+        self.resolutions
+    }
+
+    fn declare_item(&mut self, item: &Item) {
+        let Some((name, flags)) = item_symbol(item) else {
+            return;
+        };
+        let Some(id) = self.declare(name, flags) else {
+            return;
+        };
+
+        if let ItemKind::Binding(binding) = &item.kind {
+            self.set_binding_attributes(&id, binding);
+        }
+
+        self.set_doc(&id, item.doc.as_ref());
+    }
+
+    fn resolve_item(&mut self, item: &Item) {
+        match &item.kind {
+            ItemKind::Function(function) => self.resolve_function(function),
+            ItemKind::Binding(binding) => {
+                // Already declared with the other items
+                if let Some(value) = &binding.value {
+                    self.resolve_expr(value);
+                }
+
+                // Types may refer to items declared after the binding
+                let ty = binding.ty.as_ref().and_then(|ty| self.resolve_type(ty));
+                let scope = self
+                    .context
+                    .scopes
+                    .current()
+                    .expect("item outside of a scope");
+                let id = SymbolId {
+                    scope,
+                    name: binding.name.name.clone(),
+                };
+
+                // Skips a duplicate, whose name is bound to the first declaration
+                let declared = self.context.scopes.symbol(&id).and_then(Symbol::span);
+                if declared == Some(binding.name.span) {
+                    self.set_type(&id, ty);
+                }
+            }
+            ItemKind::Module(_) | ItemKind::Import(_) | ItemKind::Error => {}
+        }
+    }
+
+    fn resolve_function(&mut self, function: &Function) {
+        self.context.scopes.push_scope(ScopeKind::Function);
+
+        for param in &function.params {
+            let ty = self.resolve_type(&param.ty);
+            if let Some(id) = self.declare(&param.name, SymbolFlags::VARIABLE) {
+                self.set_type(&id, ty);
+            }
+        }
+        self.resolve_block(&function.body, ScopeKind::Block);
+
+        self.context.scopes.pop_scope();
+    }
+
+    fn resolve_block(&mut self, block: &Block, kind: ScopeKind) {
+        self.context.scopes.push_scope(kind);
+        self.resolve_statements(&block.statements);
+        self.context.scopes.pop_scope();
+    }
+
+    fn resolve_statements(&mut self, statements: &[Stmt]) {
+        for statement in statements {
+            self.resolve_statement(statement);
+        }
+    }
+
+    fn resolve_statement(&mut self, statement: &Stmt) {
+        match &statement.kind {
+            StmtKind::Binding(binding) => self.resolve_binding(binding, statement.doc.as_ref()),
+            StmtKind::Expr(expr) => self.resolve_expr(expr),
+            StmtKind::Block(block) => self.resolve_block(block, ScopeKind::Block),
+            StmtKind::If(if_stmt) => self.resolve_if(if_stmt),
+            StmtKind::While(while_stmt) => {
+                self.resolve_expr(&while_stmt.condition);
+                self.resolve_block(&while_stmt.body, ScopeKind::Loop);
+            }
+            StmtKind::For(for_stmt) => {
+                self.resolve_expr(&for_stmt.iterable);
+
+                self.context.scopes.push_scope(ScopeKind::Loop);
+                self.declare(&for_stmt.binding, SymbolFlags::VARIABLE);
+                self.resolve_statements(&for_stmt.body.statements);
+                self.context.scopes.pop_scope();
+            }
+            StmtKind::Loop(body) => self.resolve_block(body, ScopeKind::Loop),
+            StmtKind::Repeat(repeat) => {
+                self.resolve_block(&repeat.body, ScopeKind::Loop);
+                self.resolve_expr(&repeat.condition);
+            }
+            StmtKind::Match(match_stmt) => {
+                self.resolve_expr(&match_stmt.scrutinee);
+
+                for arm in &match_stmt.arms {
+                    self.context.scopes.push_scope(ScopeKind::Block);
+                    if let PatternKind::Binding(name) = &arm.pattern.kind {
+                        self.declare(name, SymbolFlags::VARIABLE);
+                    }
+                    self.resolve_statement(&arm.body);
+                    self.context.scopes.pop_scope();
+                }
+            }
+            StmtKind::Defer(deferred) => self.resolve_statement(deferred),
+            StmtKind::Return(Some(value)) => self.resolve_expr(value),
+            StmtKind::Return(None) | StmtKind::Break | StmtKind::Continue | StmtKind::Error => {}
+        }
+    }
+
+    fn resolve_binding(&mut self, binding: &Binding, doc: Option<&Atom>) {
+        // The binding is not visible from its own value or type
+        if let Some(value) = &binding.value {
+            self.resolve_expr(value);
+        }
+        let ty = binding.ty.as_ref().and_then(|ty| self.resolve_type(ty));
+
+        let flags = match binding.mutability {
+            Mutability::Const => SymbolFlags::CONST,
+            Mutability::Var => SymbolFlags::VARIABLE,
+        };
+        if let Some(id) = self.declare(&binding.name, flags) {
+            self.set_binding_attributes(&id, binding);
+            self.set_type(&id, ty);
+            self.set_doc(&id, doc);
+        }
+    }
+
+    fn resolve_if(&mut self, if_stmt: &IfStmt) {
+        self.resolve_expr(&if_stmt.condition);
+        self.resolve_block(&if_stmt.then_branch, ScopeKind::Block);
+
+        match if_stmt.else_branch.as_deref() {
+            Some(ElseBranch::If(else_if)) => self.resolve_if(else_if),
+            Some(ElseBranch::Block(block)) => self.resolve_block(block, ScopeKind::Block),
+            None => {}
+        }
+    }
+
+    fn resolve_expr(&mut self, expr: &Expr) {
+        match &expr.kind {
+            ExprKind::Literal(_) => {}
+            ExprKind::Identifier(ident) => {
+                self.resolve_use(ident);
+            }
+            ExprKind::Unary { operand, .. } => self.resolve_expr(operand),
+            ExprKind::Binary { lhs, rhs, .. } => {
+                self.resolve_expr(lhs);
+                self.resolve_expr(rhs);
+            }
+            ExprKind::Assign { target, value, .. } => {
+                self.resolve_expr(target);
+                self.resolve_expr(value);
+            }
+            ExprKind::Call { callee, args } => {
+                self.resolve_expr(callee);
+                for arg in args {
+                    self.resolve_expr(arg);
+                }
+            }
+            ExprKind::Index { target, index } => {
+                self.resolve_expr(target);
+                self.resolve_expr(index);
+            }
+            // Members belong to the type of the target
+            ExprKind::Member { target, .. } => self.resolve_expr(target),
+        }
+    }
+
+    /// Declares `name` in the current scope along with its span and module, reporting a duplicate
+    /// of the same scope or, if enabled, the shadowing of an enclosing one. Returns the symbol
+    /// unless it is a duplicate.
+    fn declare(&mut self, name: &Ident, flags: SymbolFlags) -> Option<SymbolId> {
+        let module = self.resolutions.module_scopes[&self.module];
+        let scopes = &mut self.context.scopes;
+        let current = scopes.current().expect("declaration outside of a scope");
+
+        // Module scopes have no parent, so items never shadow anything
+        let shadowed = scopes
+            .parent(current)
+            .and_then(|parent| scopes.lookup_from(parent, name.name.clone()))
+            .and_then(|(_, symbol)| symbol.span());
+
+        match scopes.add_symbol(name.name.clone(), flags) {
+            Ok(id) => {
+                scopes
+                    .symbol_mut(&id)
+                    .expect("symbol was just declared")
+                    .set_attribute(SymbolAttribute::Span, SymbolAttributeValue::Span(name.span))
+                    .set_attribute(
+                        SymbolAttribute::Module,
+                        SymbolAttributeValue::Module(module),
+                    );
+
+                if let Some(shadowed) = shadowed.filter(|_| self.context.warns_shadowing()) {
+                    self.context
+                        .add_error(error_builders::build_shadowed_name_warning(
+                            name.span, shadowed, &name.name,
+                        ));
+                }
+
+                Some(id)
+            }
+            Err(existing) => {
+                let previous = scopes
+                    .symbol(&existing)
+                    .and_then(Symbol::span)
+                    .expect("declared symbols have a span");
+
+                self.context
+                    .add_error(error_builders::build_duplicate_definition_error(
+                        name.span, previous, &name.name,
+                    ));
+
+                None
+            }
+        }
+    }
+
+    /// Sets the mutability and constant value of the symbol declared by `binding`
+    fn set_binding_attributes(&mut self, symbol: &SymbolId, binding: &Binding) {
+        let symbol = self
+            .context
+            .scopes
+            .symbol_mut(symbol)
+            .expect("symbol was declared");
+
+        let mutable = binding.mutability == Mutability::Var;
+        symbol.set_attribute(
+            SymbolAttribute::Mutable,
+            SymbolAttributeValue::Mutable(mutable),
+        );
+
+        let value = binding.value.as_ref().and_then(constant_value);
+        if let (Mutability::Const, Some(value)) = (binding.mutability, value) {
+            symbol.set_attribute(
+                SymbolAttribute::Constant,
+                SymbolAttributeValue::Constant(value),
+            );
+        }
+    }
+
+    /// Sets the type of `symbol`, unless its declaration has none or it failed to resolve
+    fn set_type(&mut self, symbol: &SymbolId, ty: Option<TypeRef>) {
+        if let Some(ty) = ty {
+            let symbol = self
+                .context
+                .scopes
+                .symbol_mut(symbol)
+                .expect("symbol was declared");
+            symbol.set_attribute(SymbolAttribute::Type, SymbolAttributeValue::Type(ty));
+        }
+    }
+
+    /// Attaches the doc comments of its declaration to `symbol`
+    fn set_doc(&mut self, symbol: &SymbolId, doc: Option<&Atom>) {
+        if let Some(doc) = doc {
+            let symbol = self
+                .context
+                .scopes
+                .symbol_mut(symbol)
+                .expect("symbol was declared");
+            symbol.set_attribute(SymbolAttribute::Doc, SymbolAttributeValue::Doc(doc.clone()));
+        }
+    }
+
+    /// Resolves a named type to the symbol declaring it, the same way as any other use
+    fn resolve_type(&mut self, ty: &Type) -> Option<TypeRef> {
+        match &ty.kind {
+            TypeKind::Primitive(primitive) => Some(TypeRef::Primitive(*primitive)),
+            TypeKind::Named(name) => self.resolve_use(name).map(TypeRef::Symbol),
+        }
+    }
+
+    /// Binds `ident` to the symbol it refers to from the current scope, looking through the
+    /// enclosing scopes then the public items of the imported modules. Returns the symbol unless
+    /// the name is undeclared.
+    fn resolve_use(&mut self, ident: &Ident) -> Option<SymbolId> {
+        let scopes = &self.context.scopes;
+        let current = scopes.current().expect("identifier outside of a scope");
+
+        let symbol = match scopes.lookup_from(current, ident.name.clone()) {
+            Some((scope, _)) => Some(scope),
+            None => self.lookup_import(&ident.name),
+        };
+
+        match symbol {
+            Some(scope) => {
+                let id = SymbolId {
+                    scope,
+                    name: ident.name.clone(),
+                };
+
+                if let Some(symbol) = self.context.scopes.symbol_mut(&id) {
+                    symbol.record_usage();
+                }
+                self.resolutions.uses.insert(ident.span, id.clone());
+
+                Some(id)
+            }
+            None => {
+                let suggestion = self.suggest(&ident.name);
+                self.context
+                    .add_error(error_builders::build_undeclared_name_error(
+                        ident.span,
+                        &ident.name,
+                        suggestion.as_deref(),
+                    ));
+
+                None
+            }
+        }
+    }
+
+    /// Looks `name` up among the public items of the modules imported by the current one, in
+    /// import order, returning the scope of the module declaring it. Private items are not
+    /// visible to importers.
+    fn lookup_import(&self, name: &Atom) -> Option<ScopeId> {
+        self.imported_scopes().find(|&scope| {
+            self.context
+                .scopes
+                .scope(scope)
+                .symbols
+                .get_symbol(name.clone())
+                .is_some_and(|symbol| symbol.flags.contains(SymbolFlags::PUB))
+        })
+    }
+
+    /// Scopes of the modules imported by the current one, in import order
+    fn imported_scopes(&self) -> impl Iterator<Item = ScopeId> + '_ {
+        let module_scopes = &self.resolutions.module_scopes;
+
+        self.modules
+            .get(self.module)
+            .imports
+            .iter()
+            .map(move |import| module_scopes[&import.module])
+    }
+
+    /// Returns the visible name closest to `name` by edit distance, if close enough to be a typo
+    fn suggest(&self, name: &str) -> Option<Atom> {
+        let scopes = &self.context.scopes;
+        let current = scopes.current()?;
+
+        let visible = scopes
+            .visible_symbols(current)
+            .map(|(_, symbol)| symbol.name.clone());
+
+        let imported = self
+            .imported_scopes()
+            .flat_map(|scope| scopes.scope(scope).symbols.iter())
+            .filter(|symbol| symbol.flags.contains(SymbolFlags::PUB))
+            .map(|symbol| symbol.name.clone());
+
+        let max_distance = (name.chars().count() / 3).max(1);
+
+        visible
+            .chain(imported)
+            .map(|candidate| (edit_distance(name, &candidate), candidate))
+            .filter(|(distance, _)| *distance <= max_distance)
+            .min()
+            .map(|(_, candidate)| candidate)
+    }
+}
+
+/// Name and flags of the symbol declared by `item`, if any
+fn item_symbol(item: &Item) -> Option<(&Ident, SymbolFlags)> {
+    let (name, mut flags) = match &item.kind {
+        ItemKind::Function(function) => (&function.name, SymbolFlags::FUNC),
+        ItemKind::Binding(binding) => match binding.mutability {
+            Mutability::Const => (&binding.name, SymbolFlags::CONST),
+            Mutability::Var => (&binding.name, SymbolFlags::VARIABLE),
+        },
+        ItemKind::Module(_) | ItemKind::Import(_) | ItemKind::Error => return None,
+    };
+
+    flags |= SymbolFlags::GLOBAL;
+    if item.visibility == Visibility::Public {
+        flags |= SymbolFlags::PUB;
+    }
+
+    Some((name, flags))
+}
+
+/// Value of a constant known from its initializer alone, ie: a literal, possibly negated
+fn constant_value(expr: &Expr) -> Option<ConstantValue> {
+    let value = match &expr.kind {
+        ExprKind::Literal(literal) => match literal {
+            Literal::Integer(value, _) => ConstantValue::Integer(i128::from(*value)),
+            Literal::Float(value, _) => ConstantValue::Float(*value),
+            Literal::String(value) => ConstantValue::String(value.clone()),
+            Literal::Character(value) => ConstantValue::Character(*value),
+            Literal::Boolean(value) => ConstantValue::Boolean(*value),
+        },
+        // Literals are unsigned, negative constants are negated ones, eg: `-128i8`
+        ExprKind::Unary {
+            op: UnaryOp::Neg,
+            operand,
+        } => match constant_value(operand)? {
+            ConstantValue::Integer(value) => ConstantValue::Integer(-value),
+            ConstantValue::Float(value) => ConstantValue::Float(-value),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some(value)
+}
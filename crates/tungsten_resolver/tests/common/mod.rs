@@ -0,0 +1,81 @@
+//! Helpers shared by the resolver tests, each using only some of them.
+#![allow(dead_code)]
+
+use std::path::Path;
+
+use tungsten_context::{CompilerContext, FileId, Span};
+use tungsten_modules::ModuleLoader;
+use tungsten_resolver::{Resolutions, Resolver};
+use tungsten_symbols::{ScopeTree, Symbol};
+
+/// Resolutions, symbols and diagnostics of a program
+pub struct Resolved<'a> {
+    pub source: &'a str,
+    pub file: FileId,
+    pub resolutions: Resolutions,
+    pub scopes: ScopeTree,
+    /// Code, message and notes of each diagnostic, followed by the source of its labels
+    pub diagnostics: Vec<String>,
+}
+
+/// Loads and resolves `source` as the main file, warning about shadowing if `warn_shadowing` is set
+pub fn resolve(source: &str, warn_shadowing: bool) -> Resolved<'_> {
+    resolve_file(Path::new("test.tung"), source, warn_shadowing)
+}
+
+/// Loads and resolves the main file at `path`, whose source is `source`, along with the modules it
+/// declares and imports
+pub fn resolve_file<'a>(path: &Path, source: &'a str, warn_shadowing: bool) -> Resolved<'a> {
+    let mut ctx = CompilerContext::new(path, source, Path::new("target"));
+    ctx.set_warn_shadowing(warn_shadowing);
+
+    let modules = ModuleLoader::new(&mut ctx).load();
+    let resolutions = Resolver::new(&mut ctx, &modules).resolve();
+
+    let diagnostics = ctx
+        .errors()
+        .iter()
+        .map(|diagnostic| {
+            let labels: Vec<&str> = diagnostic
+                .labels
+                .iter()
+                .map(|label| &ctx.sources().get(label.file_id).source()[label.range.clone()])
+                .collect();
+
+            format!(
+                "{}: {} [{}] {:?}",
+                diagnostic.code.as_deref().unwrap_or_default(),
+                diagnostic.message,
+                diagnostic.notes.join("; "),
+                labels
+            )
+        })
+        .collect();
+
+    Resolved {
+        source,
+        file: ctx.main_file(),
+        resolutions,
+        scopes: std::mem::take(&mut ctx.scopes),
+        diagnostics,
+    }
+}
+
+impl Resolved<'_> {
+    /// Span of the `n`th occurrence of `name` in the source
+    pub fn span(&self, name: &str, n: usize) -> Span {
+        let (start, _) = self.source.match_indices(name).nth(n).unwrap();
+        Span::new(self.file, start..start + name.len())
+    }
+
+    /// Symbol the `n`th occurrence of `name` resolves to
+    pub fn symbol_of(&self, name: &str, n: usize) -> Option<&Symbol> {
+        let symbol = self.resolutions.resolved(self.span(name, n))?;
+        self.scopes.symbol(symbol)
+    }
+
+    /// Span of the declaration the `n`th occurrence of `name` resolves to
+    pub fn declaration_of(&self, name: &str, n: usize) -> Option<Span> {
+        self.symbol_of(name, n)?.span()
+    }
+}
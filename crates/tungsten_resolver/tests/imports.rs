@@ -0,0 +1,28 @@
+//! Name resolution across the modules of the fixture projects in `tests/fixtures`.
+
+use std::{fs, path::PathBuf};
+
+mod common;
+
+#[test]
+fn only_public_items_of_imported_modules_are_visible() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/imports/main.tung");
+    let source = fs::read_to_string(&path).unwrap();
+    let resolved = common::resolve_file(&path, &source, false);
+
+    // `secret` is private to `util::math`, and `util` is declared but not imported
+    assert_eq!(
+        resolved.diagnostics,
+        vec![
+            "E034: Use of undeclared name `secret` [] [\"secret\"]",
+            "E034: Use of undeclared name `helper` [] [\"helper\"]",
+            "E034: Use of undeclared name `ad` [A similar name exists: `add`] [\"ad\"]",
+        ]
+    );
+
+    let add = resolved
+        .declaration_of("add", 0)
+        .expect("`add` is imported");
+    assert_ne!(add.file, resolved.file, "`add` is declared in `util::math`");
+    assert_eq!(&*resolved.symbol_of("add", 0).unwrap().name, "add");
+}
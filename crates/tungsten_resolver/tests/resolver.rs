@@ -0,0 +1,250 @@
+//! Name resolution of single-file programs.
+
+mod common;
+
+use common::resolve;
+use tungsten_symbols::{ConstantValue, TypeRef};
+use tungsten_utils::{atom, Atom, PrimitiveType};
+
+#[test]
+fn uses_are_bound_to_their_declaration() {
+    let resolved = resolve(
+        "
+func main(count: i32) {
+    var total = helper(count);
+    for item in total {
+        total += item;
+    }
+}
+
+func helper(value: i32) -> i32 {
+    |> value;
+}",
+        false,
+    );
+    assert_eq!(resolved.diagnostics, Vec::<String>::new());
+
+    // `helper` is used before its declaration, as items are visible from the whole module
+    for (name, use_index, declaration_index) in [
+        ("helper", 0, 1),
+        ("count", 1, 0),
+        ("total", 1, 0),
+        ("total", 2, 0),
+        ("item", 1, 0),
+        ("value", 1, 0),
+    ] {
+        assert_eq!(
+            resolved.declaration_of(name, use_index),
+            Some(resolved.span(name, declaration_index)),
+            "use {use_index} of `{name}`"
+        );
+    }
+}
+
+#[test]
+fn undeclared_name_with_suggestion() {
+    let resolved = resolve(
+        "
+func main() {
+    var counter = 1;
+    countr += 1;
+    unrelated();
+}",
+        false,
+    );
+
+    assert_eq!(
+        resolved.diagnostics,
+        vec![
+            "E034: Use of undeclared name `countr` [A similar name exists: `counter`] [\"countr\"]",
+            "E034: Use of undeclared name `unrelated` [] [\"unrelated\"]",
+        ]
+    );
+    assert!(resolved.declaration_of("countr", 0).is_none());
+}
+
+#[test]
+fn locals_are_only_visible_after_their_declaration() {
+    let resolved = resolve(
+        "
+func main() {
+    var early = late;
+    var late = 1;
+    {
+        var inner = 2;
+    }
+    var outer = inner;
+}",
+        false,
+    );
+
+    assert_eq!(resolved.diagnostics.len(), 2, "{:?}", resolved.diagnostics);
+    assert!(resolved.diagnostics[0].contains("`late`"));
+    assert!(resolved.diagnostics[1].contains("`inner`"));
+}
+
+#[test]
+fn duplicate_definitions_label_both_sites() {
+    let resolved = resolve(
+        "
+func twice() {}
+const twice = 1;
+
+func main() {
+    var x = 1;
+    var x = 2;
+}",
+        false,
+    );
+
+    assert_eq!(
+        resolved.diagnostics,
+        vec![
+            "E035: `twice` is defined multiple times [`twice` can only be defined once in the \
+             same scope] [\"twice\", \"twice\"]",
+            "E035: `x` is defined multiple times [`x` can only be defined once in the same \
+             scope] [\"x\", \"x\"]",
+        ]
+    );
+}
+
+#[test]
+fn shadowing_warnings_are_optional() {
+    let source = "
+const limit = 10;
+
+func main(limit: i32) {
+    loop {
+        var limit = 1;
+    }
+}";
+
+    assert_eq!(resolve(source, false).diagnostics, Vec::<String>::new());
+
+    let resolved = resolve(source, true);
+    assert_eq!(resolved.diagnostics.len(), 2, "{:?}", resolved.diagnostics);
+    assert!(resolved
+        .diagnostics
+        .iter()
+        .all(|diagnostic| diagnostic.starts_with("W003: `limit` shadows")));
+}
+
+#[test]
+fn declarations_carry_their_attributes() {
+    let resolved = resolve(
+        "
+## Largest size
+pub const max_size: u32 = 64;
+var count = 0;
+
+func main(extra: u32) {
+    count = max_size + extra;
+    count += 1;
+}",
+        false,
+    );
+    assert_eq!(resolved.diagnostics, Vec::<String>::new());
+
+    let max_size = resolved.symbol_of("max_size", 1).unwrap();
+    assert_eq!(max_size.doc(), Some(&atom!("Largest size")));
+    assert_eq!(max_size.ty(), Some(&TypeRef::Primitive(PrimitiveType::U32)));
+    assert_eq!(max_size.is_mutable(), Some(false));
+    assert_eq!(max_size.constant(), Some(&ConstantValue::Integer(64)));
+    assert_eq!(max_size.usages(), 1);
+
+    let count = resolved.symbol_of("count", 1).unwrap();
+    assert_eq!(count.is_mutable(), Some(true));
+    assert_eq!(count.constant(), None);
+    assert_eq!(count.usages(), 2);
+    assert_eq!(count.module(), max_size.module());
+
+    let extra = resolved.symbol_of("extra", 1).unwrap();
+    assert_eq!(extra.ty(), Some(&TypeRef::Primitive(PrimitiveType::U32)));
+    assert!(extra.module().is_some());
+}
+
+#[test]
+fn named_types_resolve_to_their_declaration() {
+    let source = "
+func main(first: size) {
+    var last: size = first;
+    var other: sise = last;
+}
+
+const size = 8;";
+    let resolved = resolve(source, false);
+    assert_eq!(
+        resolved.diagnostics,
+        vec!["E034: Use of undeclared name `sise` [A similar name exists: `size`] [\"sise\"]"]
+    );
+
+    let size = resolved
+        .resolutions
+        .resolved(resolved.span("size", 0))
+        .unwrap();
+    assert_eq!(
+        resolved.declaration_of("size", 1),
+        resolved.declaration_of("size", 0)
+    );
+    assert_eq!(resolved.symbol_of("size", 0).unwrap().usages(), 2);
+
+    let ty = Some(TypeRef::Symbol(size.clone()));
+    assert_eq!(resolved.symbol_of("first", 1).unwrap().ty(), ty.as_ref());
+    assert_eq!(resolved.symbol_of("last", 1).unwrap().ty(), ty.as_ref());
+}
+
+#[test]
+fn negated_literals_are_constant() {
+    let resolved = resolve(
+        "
+const min = -128i8;
+const low = -9223372036854775808i64;
+const high = 18446744073709551615u64;
+const scale = -1.5;
+const flag = -true;
+
+func main() {
+    |> min + low + high + scale + flag;
+}",
+        false,
+    );
+    assert_eq!(resolved.diagnostics, Vec::<String>::new());
+
+    let constant = |name| resolved.symbol_of(name, 1).unwrap().constant().cloned();
+    assert_eq!(constant("min"), Some(ConstantValue::Integer(-128)));
+    assert_eq!(
+        constant("low"),
+        Some(ConstantValue::Integer(i64::MIN.into()))
+    );
+    assert_eq!(
+        constant("high"),
+        Some(ConstantValue::Integer(u64::MAX.into()))
+    );
+    assert_eq!(constant("scale"), Some(ConstantValue::Float(-1.5)));
+    assert_eq!(constant("flag"), None);
+}
+
+#[test]
+fn local_bindings_carry_their_doc() {
+    let resolved = resolve(
+        "
+func main() {
+    ## Number of attempts
+    ## before giving up
+    const tries = 3;
+    var left = tries;
+    left -= 1;
+}",
+        false,
+    );
+    assert_eq!(resolved.diagnostics, Vec::<String>::new());
+
+    let tries = resolved.symbol_of("tries", 1).unwrap();
+    assert_eq!(
+        tries.doc(),
+        Some(&atom!("Number of attempts\nbefore giving up"))
+    );
+
+    let left = resolved.symbol_of("left", 1).unwrap();
+    assert_eq!(left.doc(), None);
+}
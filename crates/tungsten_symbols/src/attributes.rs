@@ -0,0 +1,20 @@
+/// Attributes set by the compiler, each holding a single kind of [`SymbolAttributeValue`]
+///
+/// [`SymbolAttributeValue`]: crate::SymbolAttributeValue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymbolAttribute {
+    /// [`SymbolAttributeValue::Span`](crate::SymbolAttributeValue::Span)
+    Span,
+    /// [`SymbolAttributeValue::Type`](crate::SymbolAttributeValue::Type)
+    Type,
+    /// [`SymbolAttributeValue::Module`](crate::SymbolAttributeValue::Module)
+    Module,
+    /// [`SymbolAttributeValue::Mutable`](crate::SymbolAttributeValue::Mutable)
+    Mutable,
+    /// [`SymbolAttributeValue::Constant`](crate::SymbolAttributeValue::Constant)
+    Constant,
+    /// [`SymbolAttributeValue::Doc`](crate::SymbolAttributeValue::Doc)
+    Doc,
+    /// [`SymbolAttributeValue::Usages`](crate::SymbolAttributeValue::Usages)
+    Usages,
+}
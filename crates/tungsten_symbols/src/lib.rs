@@ -1,8 +1,13 @@
 use std::collections::HashMap;
 
 use bitflags::bitflags;
-use indextree::{Arena, NodeId};
-use tungsten_utils::Atom;
+use tungsten_utils::{Atom, PrimitiveType, Span};
+
+pub use attributes::*;
+pub use scope_tree::*;
+
+mod attributes;
+mod scope_tree;
 
 bitflags! {
     #[derive(Debug, Clone)]
@@ -27,98 +32,176 @@ bitflags! {
 pub struct Symbol {
     pub name: Atom,
     pub flags: SymbolFlags,
-    pub attributes: HashMap<Atom, SymbolAttributeValue>,
+    pub attributes: HashMap<SymbolAttribute, SymbolAttributeValue>,
 }
 
-#[derive(Debug, Clone)]
-pub enum SymbolAttributeValue {}
+#[derive(Debug, Clone, PartialEq)]
+pub enum SymbolAttributeValue {
+    /// Span of the name in the declaration
+    Span(Span),
+    /// Type of the symbol, resolved from the one written in its declaration
+    Type(TypeRef),
+    /// Scope of the module defining the symbol
+    Module(ScopeId),
+    Mutable(bool),
+    /// Value of a constant known at compile time
+    Constant(ConstantValue),
+    /// Text of the doc comments of the declaration
+    Doc(Atom),
+    /// Number of uses of the symbol
+    Usages(usize),
+}
+
+/// Type referred to by a declaration, eg: the `i32` in `var a: i32`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeRef {
+    Primitive(PrimitiveType),
+    /// Type declared as a symbol, found by looking its name up from the declaration
+    Symbol(SymbolId),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstantValue {
+    /// Wide enough for the whole range of both `u64` and `i64`, eg: `-128i8`
+    Integer(i128),
+    Float(f64),
+    String(Atom),
+    Character(char),
+    Boolean(bool),
+}
+
+impl Symbol {
+    pub fn attribute(&self, attribute: SymbolAttribute) -> Option<&SymbolAttributeValue> {
+        self.attributes.get(&attribute)
+    }
+
+    pub fn set_attribute(
+        &mut self,
+        attribute: SymbolAttribute,
+        value: SymbolAttributeValue,
+    ) -> &mut Self {
+        self.attributes.insert(attribute, value);
+        self
+    }
+
+    pub fn span(&self) -> Option<Span> {
+        match self.attribute(SymbolAttribute::Span)? {
+            SymbolAttributeValue::Span(span) => Some(*span),
+            _ => None,
+        }
+    }
+
+    pub fn ty(&self) -> Option<&TypeRef> {
+        match self.attribute(SymbolAttribute::Type)? {
+            SymbolAttributeValue::Type(ty) => Some(ty),
+            _ => None,
+        }
+    }
+
+    pub fn module(&self) -> Option<ScopeId> {
+        match self.attribute(SymbolAttribute::Module)? {
+            SymbolAttributeValue::Module(module) => Some(*module),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the symbol can be assigned to, if known
+    pub fn is_mutable(&self) -> Option<bool> {
+        match self.attribute(SymbolAttribute::Mutable)? {
+            SymbolAttributeValue::Mutable(mutable) => Some(*mutable),
+            _ => None,
+        }
+    }
+
+    pub fn constant(&self) -> Option<&ConstantValue> {
+        match self.attribute(SymbolAttribute::Constant)? {
+            SymbolAttributeValue::Constant(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn doc(&self) -> Option<&Atom> {
+        match self.attribute(SymbolAttribute::Doc)? {
+            SymbolAttributeValue::Doc(doc) => Some(doc),
+            _ => None,
+        }
+    }
+
+    pub fn usages(&self) -> usize {
+        match self.attribute(SymbolAttribute::Usages) {
+            Some(SymbolAttributeValue::Usages(usages)) => *usages,
+            _ => 0,
+        }
+    }
 
+    pub fn record_usage(&mut self) {
+        let usages = self.usages() + 1;
+        self.set_attribute(
+            SymbolAttribute::Usages,
+            SymbolAttributeValue::Usages(usages),
+        );
+    }
+}
+
+/// Symbols declared in a single scope, see [`ScopeTree`] for lookups through enclosing scopes
 #[derive(Debug, Clone, Default)]
 pub struct SymbolTable {
     symbols: HashMap<Atom, Symbol>,
-    parent: Option<NodeId>,
 }
 
 impl SymbolTable {
-    pub fn new(parent: Option<NodeId>) -> Self {
-        Self {
-            symbols: HashMap::new(),
-            parent,
-        }
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    pub fn add_symbol(&mut self, name: Atom, flags: SymbolFlags) {
+    /// Declares `name`, returning `false` and leaving the table unchanged if it already is
+    pub fn add_symbol(&mut self, name: Atom, flags: SymbolFlags) -> bool {
+        if self.symbols.contains_key(&name) {
+            return false;
+        }
+
         let symbol = Symbol {
             name: name.clone(),
             flags,
             attributes: HashMap::new(),
         };
-
         self.symbols.insert(name, symbol);
+
+        true
     }
 
-    pub fn set_attribute<'a>(
-        &'a mut self,
+    pub fn set_attribute(
+        &mut self,
         name: Atom,
-        attribute: Atom,
+        attribute: SymbolAttribute,
         value: SymbolAttributeValue,
-        arena: Option<&'a mut Arena<SymbolTable>>,
     ) {
-        if let Some(symbol) = self.get_symbol_mut(name, arena) {
-            symbol.attributes.insert(attribute, value);
+        if let Some(symbol) = self.get_symbol_mut(name) {
+            symbol.set_attribute(attribute, value);
         }
     }
 
-    pub fn get_attribute<'a>(
-        &'a self,
+    pub fn get_attribute(
+        &self,
         name: Atom,
-        attribute: Atom,
-        arena: Option<&'a Arena<SymbolTable>>,
-    ) -> Option<&'a SymbolAttributeValue> {
-        self.get_symbol(name, arena)?.attributes.get(&attribute)
+        attribute: SymbolAttribute,
+    ) -> Option<&SymbolAttributeValue> {
+        self.get_symbol(name)?.attribute(attribute)
     }
 
-    pub fn get_symbol_mut<'a>(
-        &'a mut self,
-        name: Atom,
-        arena: Option<&'a mut Arena<SymbolTable>>,
-    ) -> Option<&'a mut Symbol> {
-        if let Some(symbol) = self.symbols.get_mut(&name) {
-            return Some(symbol);
-        }
-
-        if let Some(arena) = arena {
-            if let Some(parent_id) = self.parent {
-                if let Some(parent_table) = arena.get_mut(parent_id) {
-                    return parent_table.get_mut().get_symbol_mut(name, None);
-                }
-            }
-        }
-
-        None
+    pub fn get_symbol_mut(&mut self, name: Atom) -> Option<&mut Symbol> {
+        self.symbols.get_mut(&name)
     }
 
-    pub fn get_symbol<'a>(
-        &'a self,
-        name: Atom,
-        arena: Option<&'a Arena<SymbolTable>>,
-    ) -> Option<&'a Symbol> {
-        if let Some(symbol) = self.symbols.get(&name) {
-            return Some(symbol);
-        }
-
-        if let Some(arena) = arena {
-            if let Some(parent_id) = self.parent {
-                if let Some(parent_table) = arena.get(parent_id) {
-                    return parent_table.get().get_symbol(name, Some(arena));
-                }
-            }
-        }
-
-        None
+    pub fn get_symbol(&self, name: Atom) -> Option<&Symbol> {
+        self.symbols.get(&name)
     }
 
     pub fn contains(&self, name: Atom) -> bool {
         self.symbols.contains_key(&name)
     }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Symbol> {
+        self.symbols.values()
+    }
 }
@@ -0,0 +1,165 @@
+use std::collections::HashSet;
+
+use indextree::{Arena, NodeId};
+use tungsten_utils::Atom;
+
+use crate::{Symbol, SymbolFlags, SymbolTable};
+
+/// Identifies a scope of a [`ScopeTree`]
+pub type ScopeId = NodeId;
+
+/// Identifies a symbol of a [`ScopeTree`], names being unique within a scope
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SymbolId {
+    pub scope: ScopeId,
+    pub name: Atom,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScopeKind {
+    /// Top-level items of a module
+    Module,
+    /// Parameters of a function
+    Function,
+    Block,
+    /// Body of a `loop`, `while`, `for` or `repeat` loop
+    Loop,
+}
+
+#[derive(Debug, Clone)]
+pub struct Scope {
+    pub kind: ScopeKind,
+    pub symbols: SymbolTable,
+}
+
+/// Nested scopes of a compilation, each module scope being a root. Scopes are kept once popped,
+/// so the symbols visible at any point can still be queried after a pass.
+#[derive(Debug, Clone, Default)]
+pub struct ScopeTree {
+    arena: Arena<Scope>,
+    /// Innermost scope pushed and not yet popped
+    current: Option<ScopeId>,
+}
+
+impl ScopeTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens a scope nested in the current one, or a new root if there is none, and makes it the
+    /// current scope
+    pub fn push_scope(&mut self, kind: ScopeKind) -> ScopeId {
+        let scope = self.arena.new_node(Scope {
+            kind,
+            symbols: SymbolTable::new(),
+        });
+
+        if let Some(current) = self.current {
+            current.append(scope, &mut self.arena);
+        }
+        self.current = Some(scope);
+
+        scope
+    }
+
+    /// Closes the current scope, returning it, its parent becoming the current scope
+    pub fn pop_scope(&mut self) -> ScopeId {
+        let scope = self.current.expect("no scope to pop");
+        self.current = self.parent(scope);
+
+        scope
+    }
+
+    /// Makes `scope`, which was pushed earlier, the current scope again
+    pub fn enter(&mut self, scope: ScopeId) {
+        self.current = Some(scope);
+    }
+
+    pub fn current(&self) -> Option<ScopeId> {
+        self.current
+    }
+
+    pub fn scope(&self, id: ScopeId) -> &Scope {
+        self.arena[id].get()
+    }
+
+    pub fn scope_mut(&mut self, id: ScopeId) -> &mut Scope {
+        self.arena[id].get_mut()
+    }
+
+    pub fn parent(&self, id: ScopeId) -> Option<ScopeId> {
+        self.arena[id].parent()
+    }
+
+    /// Returns the innermost scope of `kind` enclosing `scope`, `scope` included
+    pub fn enclosing(&self, scope: ScopeId, kind: ScopeKind) -> Option<ScopeId> {
+        scope
+            .ancestors(&self.arena)
+            .find(|&ancestor| self.scope(ancestor).kind == kind)
+    }
+
+    /// Declares a symbol in the current scope, returning the symbol already declared with that
+    /// name in the scope as an error
+    pub fn add_symbol(&mut self, name: Atom, flags: SymbolFlags) -> Result<SymbolId, SymbolId> {
+        let scope = self.current.expect("no current scope");
+        let added = self
+            .scope_mut(scope)
+            .symbols
+            .add_symbol(name.clone(), flags);
+
+        let id = SymbolId { scope, name };
+        if added {
+            Ok(id)
+        } else {
+            Err(id)
+        }
+    }
+
+    pub fn symbol(&self, id: &SymbolId) -> Option<&Symbol> {
+        self.scope(id.scope).symbols.get_symbol(id.name.clone())
+    }
+
+    pub fn symbol_mut(&mut self, id: &SymbolId) -> Option<&mut Symbol> {
+        self.scope_mut(id.scope)
+            .symbols
+            .get_symbol_mut(id.name.clone())
+    }
+
+    /// Looks `name` up from the current scope outwards
+    pub fn lookup(&self, name: Atom) -> Option<&Symbol> {
+        let (_, symbol) = self.lookup_from(self.current?, name)?;
+        Some(symbol)
+    }
+
+    pub fn lookup_mut(&mut self, name: Atom) -> Option<&mut Symbol> {
+        self.lookup_from_mut(self.current?, name)
+    }
+
+    /// Looks `name` up from `scope` outwards, returning the scope declaring it as well
+    pub fn lookup_from(&self, scope: ScopeId, name: Atom) -> Option<(ScopeId, &Symbol)> {
+        scope.ancestors(&self.arena).find_map(|ancestor| {
+            let symbol = self.scope(ancestor).symbols.get_symbol(name.clone())?;
+            Some((ancestor, symbol))
+        })
+    }
+
+    pub fn lookup_from_mut(&mut self, scope: ScopeId, name: Atom) -> Option<&mut Symbol> {
+        let (declaring, _) = self.lookup_from(scope, name.clone())?;
+        self.scope_mut(declaring).symbols.get_symbol_mut(name)
+    }
+
+    /// Symbols visible from `scope`, innermost first, leaving out the shadowed ones
+    pub fn visible_symbols(&self, scope: ScopeId) -> impl Iterator<Item = (ScopeId, &Symbol)> {
+        let mut seen = HashSet::new();
+
+        scope
+            .ancestors(&self.arena)
+            .flat_map(|ancestor| {
+                self.scope(ancestor)
+                    .symbols
+                    .iter()
+                    .map(move |symbol| (ancestor, symbol))
+            })
+            .filter(move |(_, symbol)| seen.insert(symbol.name.clone()))
+    }
+}
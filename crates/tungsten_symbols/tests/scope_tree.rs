@@ -0,0 +1,114 @@
+//! Lookups through nested scopes of a `ScopeTree`.
+
+use tungsten_symbols::{ScopeKind, ScopeTree, SymbolFlags};
+use tungsten_utils::{atom, Atom};
+
+/// module { x, f } > function { x } > loop > block { y }
+fn nested() -> ScopeTree {
+    let mut scopes = ScopeTree::new();
+
+    scopes.push_scope(ScopeKind::Module);
+    scopes
+        .add_symbol(atom!("x"), SymbolFlags::GLOBAL | SymbolFlags::VARIABLE)
+        .unwrap();
+    scopes
+        .add_symbol(atom!("f"), SymbolFlags::GLOBAL | SymbolFlags::FUNC)
+        .unwrap();
+
+    scopes.push_scope(ScopeKind::Function);
+    scopes
+        .add_symbol(atom!("x"), SymbolFlags::VARIABLE)
+        .unwrap();
+
+    scopes.push_scope(ScopeKind::Loop);
+    scopes.push_scope(ScopeKind::Block);
+    scopes.add_symbol(atom!("y"), SymbolFlags::CONST).unwrap();
+
+    scopes
+}
+
+#[test]
+fn lookup_walks_every_enclosing_scope() {
+    let scopes = nested();
+
+    let f = scopes
+        .lookup(atom!("f"))
+        .expect("`f` is declared in the module scope");
+    assert!(f.flags.contains(SymbolFlags::FUNC));
+
+    let x = scopes.lookup(atom!("x")).unwrap();
+    assert!(
+        !x.flags.contains(SymbolFlags::GLOBAL),
+        "the parameter shadows the global"
+    );
+
+    assert!(scopes.lookup(atom!("z")).is_none());
+}
+
+#[test]
+fn lookup_mut_walks_every_enclosing_scope() {
+    let mut scopes = nested();
+
+    let f = scopes
+        .lookup_mut(atom!("f"))
+        .expect("`f` is three scopes up");
+    f.flags |= SymbolFlags::PUB;
+
+    assert!(scopes
+        .lookup(atom!("f"))
+        .unwrap()
+        .flags
+        .contains(SymbolFlags::PUB));
+}
+
+#[test]
+fn popped_scopes_stay_queryable() {
+    let mut scopes = nested();
+
+    let block = scopes.pop_scope();
+    let loop_scope = scopes.pop_scope();
+    assert!(scopes.lookup(atom!("y")).is_none());
+
+    let (declaring, _) = scopes.lookup_from(block, atom!("y")).unwrap();
+    assert_eq!(declaring, block);
+    assert_eq!(scopes.enclosing(block, ScopeKind::Loop), Some(loop_scope));
+    assert_eq!(scopes.parent(block), Some(loop_scope));
+
+    let function = scopes.pop_scope();
+    assert_eq!(scopes.scope(function).kind, ScopeKind::Function);
+    scopes.pop_scope();
+    assert_eq!(scopes.current(), None);
+}
+
+#[test]
+fn visible_symbols_leave_out_shadowed_ones() {
+    let scopes = nested();
+    let block = scopes.current().unwrap();
+
+    let mut visible: Vec<(Atom, bool)> = scopes
+        .visible_symbols(block)
+        .map(|(_, symbol)| {
+            let global = symbol.flags.contains(SymbolFlags::GLOBAL);
+            (symbol.name.clone(), global)
+        })
+        .collect();
+    visible.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    assert_eq!(
+        visible,
+        vec![(atom!("f"), true), (atom!("x"), false), (atom!("y"), false)]
+    );
+}
+
+#[test]
+fn redeclaration_keeps_the_first_symbol() {
+    let mut scopes = nested();
+
+    let existing = scopes
+        .add_symbol(atom!("y"), SymbolFlags::VARIABLE)
+        .unwrap_err();
+
+    assert_eq!(existing.scope, scopes.current().unwrap());
+    let symbol = scopes.symbol(&existing).unwrap();
+    assert!(symbol.flags.contains(SymbolFlags::CONST));
+}
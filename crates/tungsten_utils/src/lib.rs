@@ -1,8 +1,14 @@
 extern crate string_cache;
 pub use atom::*;
+pub use edit_distance::*;
+pub use primitive_type::*;
+pub use span::*;
 
 #[macro_use]
 mod atom;
+mod edit_distance;
+mod primitive_type;
+mod span;
 
 pub fn guess_host_target_triple() -> String {
     let arch = target::arch();
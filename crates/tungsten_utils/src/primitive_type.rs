@@ -0,0 +1,74 @@
+/// Built-in type, named by a type keyword, eg: `i32`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrimitiveType {
+    Void,
+    Nil,
+    String,
+    Boolean,
+    Character,
+    /// Pointer-sized unsigned integer
+    UnsignedInteger,
+    /// Pointer-sized signed integer
+    SignedInteger,
+    Float,
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+    F32,
+    F64,
+}
+
+impl PrimitiveType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Void => "void",
+            Self::Nil => "nil",
+            Self::String => "str",
+            Self::Boolean => "bool",
+            Self::Character => "char",
+            Self::UnsignedInteger => "uint",
+            Self::SignedInteger => "int",
+            Self::Float => "float",
+            Self::I8 => "i8",
+            Self::I16 => "i16",
+            Self::I32 => "i32",
+            Self::I64 => "i64",
+            Self::U8 => "u8",
+            Self::U16 => "u16",
+            Self::U32 => "u32",
+            Self::U64 => "u64",
+            Self::F32 => "f32",
+            Self::F64 => "f64",
+        }
+    }
+
+    pub fn is_float(self) -> bool {
+        matches!(self, Self::Float | Self::F32 | Self::F64)
+    }
+
+    /// Inclusive range of values of a sized integer type
+    pub fn integer_range(self) -> Option<(i128, i128)> {
+        let (bits, signed) = match self {
+            Self::I8 => (8, true),
+            Self::I16 => (16, true),
+            Self::I32 => (32, true),
+            Self::I64 => (64, true),
+            Self::U8 => (8, false),
+            Self::U16 => (16, false),
+            Self::U32 => (32, false),
+            Self::U64 => (64, false),
+            _ => return None,
+        };
+
+        if signed {
+            Some((-(1 << (bits - 1)), (1 << (bits - 1)) - 1))
+        } else {
+            Some((0, (1 << bits) - 1))
+        }
+    }
+}
@@ -0,0 +1,8 @@
+//! Lexes arbitrary UTF-8 input, which must never panic nor break the token invariants:
+//! `cargo fuzz run lexer corpus/lexer seeds`
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|source: &str| tungsten_fuzz::check_lexer(source));
@@ -0,0 +1,7 @@
+//! Parses arbitrary UTF-8 input, which must never panic: `cargo fuzz run parser corpus/parser seeds`
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|source: &str| tungsten_fuzz::check_parser(source));
@@ -0,0 +1,113 @@
+//! Checks shared by the fuzz targets and the regression tests replaying their crashes.
+
+use std::path::Path;
+
+use tungsten_context::CompilerContext;
+use tungsten_lexer::{Kind, Lexer, Token};
+use tungsten_parser::Parser;
+
+/// Lexes `source` with and without trivia, checking the invariants of both token streams
+pub fn check_lexer(source: &str) {
+    let tokens = lex(source, false);
+    check_tokens(source, &tokens);
+
+    let tokens = lex(source, true);
+    check_tokens(source, &tokens);
+
+    let lexemes: String = tokens.iter().map(|token| token.lexeme).collect();
+    assert_eq!(
+        lexemes, source,
+        "lexemes with trivia do not add up to the source"
+    );
+}
+
+/// Lexes and parses `source`, which must not panic whatever the errors
+pub fn check_parser(source: &str) {
+    let mut ctx = CompilerContext::new(Path::new("fuzz.tung"), source, Path::new("target"));
+    let file = ctx.main_file();
+    let tokens = Lexer::new(&mut ctx, file, source).tokenize();
+    check_tokens(source, &tokens);
+
+    let program = Parser::new(&mut ctx, tokens).parse();
+    assert!(
+        program.span.end <= source.len(),
+        "program span {:?} out of bounds",
+        program.span
+    );
+}
+
+fn lex(source: &str, preserve_trivia: bool) -> Vec<Token<'_>> {
+    let mut ctx = CompilerContext::new(Path::new("fuzz.tung"), source, Path::new("target"));
+    let file = ctx.main_file();
+    let mut lexer = Lexer::new(&mut ctx, file, source);
+    lexer.set_preserve_trivia(preserve_trivia);
+
+    lexer.tokenize()
+}
+
+/// Whitespace the lexer skips or emits as trivia
+const WHITESPACE: [char; 4] = [' ', '\t', '\r', '\n'];
+
+/// Spans are in bounds and increasing, lexemes are the source they span, only trivia and literals
+/// hold whitespace, and the stream ends with a single `Eof` token
+fn check_tokens(source: &str, tokens: &[Token]) {
+    let mut previous_end = 0;
+
+    for token in tokens {
+        let span = token.span.range();
+
+        assert!(
+            span.start <= span.end && span.end <= source.len(),
+            "span {span:?} of {token:?} out of bounds"
+        );
+        assert!(
+            span.start >= previous_end,
+            "span {span:?} of {token:?} starts before the previous token ends at {previous_end}"
+        );
+        assert_eq!(
+            source.get(span.clone()),
+            Some(token.lexeme),
+            "lexeme of {token:?} differs from the source at {span:?}"
+        );
+
+        // A token swallowing whitespace has absorbed the input of another one
+        if !token.kind.is_trivia() {
+            assert!(
+                !token.lexeme.starts_with(WHITESPACE),
+                "{token:?} starts with whitespace"
+            );
+        }
+        if !may_hold_whitespace(token.kind) {
+            assert!(
+                !token.lexeme.contains(WHITESPACE),
+                "{token:?} holds whitespace"
+            );
+        }
+
+        previous_end = span.end;
+    }
+
+    let eof_count = tokens
+        .iter()
+        .filter(|token| token.kind == Kind::Eof)
+        .count();
+    assert_eq!(eof_count, 1, "expected a single `Eof` token");
+    assert_eq!(
+        tokens.last().map(|token| token.kind),
+        Some(Kind::Eof),
+        "expected the stream to end with `Eof`"
+    );
+}
+
+fn may_hold_whitespace(kind: Kind) -> bool {
+    kind.is_trivia()
+        || matches!(
+            kind,
+            Kind::StringLiteral
+                | Kind::StringHead
+                | Kind::StringMiddle
+                | Kind::StringTail
+                | Kind::CharLiteral
+                | Kind::DocComment
+        )
+}
@@ -0,0 +1,30 @@
+//! Replays the inputs under `regressions/<target>`, each a former crash of that fuzz target.
+//!
+//! To turn a crash into a regression test, copy the file from `artifacts/<target>` into
+//! `regressions/<target>` under a name describing the bug.
+
+use std::{fs, path::Path};
+
+fn replay(target: &str, check: fn(&str)) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("regressions")
+        .join(target);
+
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let source = fs::read_to_string(&path).unwrap();
+
+        println!("replaying {}", path.display());
+        check(&source);
+    }
+}
+
+#[test]
+fn lexer_regressions() {
+    replay("lexer", tungsten_fuzz::check_lexer);
+}
+
+#[test]
+fn parser_regressions() {
+    replay("parser", tungsten_fuzz::check_parser);
+}